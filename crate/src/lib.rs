@@ -5,6 +5,7 @@ extern crate rand_xoshiro;
 extern crate wasm_bindgen;
 extern crate web_sys;
 
+mod reactions;
 mod species;
 mod utils;
 
@@ -29,6 +30,39 @@ pub struct Wind {
     density: u8,
 }
 
+// Velocity 是动量模式下每个细胞的速度，vx 和 vy 以 1/VELOCITY_SCALE 格每帧为单位。
+// 速度存放在和 cells 对齐的单独一层里，细胞通过 SandApi::set 移动时速度会跟着一起移动。
+#[wasm_bindgen]
+#[repr(C)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Velocity {
+    vx: i8,
+    vy: i8,
+}
+
+static ZERO_VELOCITY: Velocity = Velocity { vx: 0, vy: 0 };
+const VELOCITY_SCALE: i32 = 4;
+const MAX_VELOCITY: i32 = 16;
+const SPLASH_VELOCITY: i32 = 8;
+
+// Projectile 是脱离网格自由飞行的粒子，例如爆炸的碎片、火箭喷出的火星和岩浆溅起的熔岩。
+// x 和 y 是以格为单位的位置，vx 和 vy 是每帧移动的格数，cell 是它落地以后变回的细胞。
+#[wasm_bindgen]
+#[repr(C)]
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Projectile {
+    x: f32,
+    y: f32,
+    vx: f32,
+    vy: f32,
+    cell: Cell,
+}
+
+const MAX_PROJECTILES: usize = 1000;
+const PROJECTILE_GRAVITY: f32 = 0.15;
+const PROJECTILE_WIND: f32 = 0.002;
+const PROJECTILE_MAX_SPEED: f32 = 3.0;
+
 // Cell 代表了模拟中的一个单元，包含以下字段：
 //
 // species：细胞的物种类型（例如沙子、墙壁、植物等）。这些物种应该是通过一个 Species 枚举类型来表示的。
@@ -74,6 +108,11 @@ static EMPTY_CELL: Cell = Cell {
 // cells：一个 Vec<Cell>，用于存储宇宙中的所有细胞。
 // undo_stack：用于撤销操作的栈，保存了历史状态。这允许在模拟过程中回退到之前的状态。
 // winds 和 burns：分别表示宇宙中每个位置的风数据和烧伤状态。它们是与 Wind 类型相关的向量。
+// pressures：每个液体细胞的静水压，等于它在所属的连通液体中距离最高液面的深度。
+// structural：是否开启结构模式，开启后没有连接到地面或墙上的固体会整块掉下来。
+// velocities 和 momentum：每个细胞的速度，以及是否开启动量模式。
+// projectiles：正在空中飞行、还不属于任何格子的粒子。
+// scents：蚂蚁留下的信息素，每帧都会慢慢消散。
 // generation：宇宙当前的代数，通常用于追踪模拟的进度。
 // rng：SplitMix64 是一个伪随机数生成器，用于生成模拟中的随机事件。
 #[wasm_bindgen]
@@ -84,6 +123,15 @@ pub struct Universe {
     undo_stack: VecDeque<Vec<Cell>>,
     winds: Vec<Wind>,
     burns: Vec<Wind>,
+    pressures: Vec<u8>,
+    structural: bool,
+    clusters: Vec<u32>,
+    cluster_stamp: u32,
+    velocities: Vec<Velocity>,
+    momentum: bool,
+    projectiles: Vec<Projectile>,
+    scents: Vec<u8>,
+    reactions: Vec<Vec<usize>>,
     generation: u8,
     rng: SplitMix64,
 }
@@ -122,6 +170,11 @@ impl<'a> SandApi<'a> {
             return;
         }
         let i = self.universe.get_index(nx, ny);
+        // 细胞离开原来的位置时，把它的速度一起带走
+        if self.universe.momentum && (dx != 0 || dy != 0) && v.species != Species::Empty {
+            let origin = self.universe.get_index(self.x, self.y);
+            self.universe.velocities.swap(origin, i);
+        }
         // v.clock += 1;
         self.universe.cells[i] = v;
         self.universe.cells[i].clock = self.universe.generation.wrapping_add(1);
@@ -136,6 +189,47 @@ impl<'a> SandApi<'a> {
 
         self.universe.burns[idx] = v;
     }
+    // 从 (dx, dy) 发射一个飞行粒子，它会受重力和风的影响飞行，落地后变成 cell。
+    pub fn launch(&mut self, dx: i32, dy: i32, vx: f32, vy: f32, cell: Cell) {
+        let nx = self.x + dx;
+        let ny = self.y + dy;
+        if nx < 0 || nx > self.universe.width - 1 || ny < 0 || ny > self.universe.height - 1 {
+            return;
+        }
+        if self.universe.projectiles.len() >= MAX_PROJECTILES {
+            return;
+        }
+        self.universe.projectiles.push(Projectile {
+            x: nx as f32 + 0.5,
+            y: ny as f32 + 0.5,
+            vx,
+            vy,
+            cell,
+        });
+    }
+    pub fn get_scent(&mut self, dx: i32, dy: i32) -> u8 {
+        if !(-2..=2).contains(&dx) || !(-2..=2).contains(&dy) {
+            panic!("oob get_scent");
+        }
+        let nx = self.x + dx;
+        let ny = self.y + dy;
+        if nx < 0 || nx > self.universe.width - 1 || ny < 0 || ny > self.universe.height - 1 {
+            return 0;
+        }
+        let idx = self.universe.get_index(nx, ny);
+
+        self.universe.scents[idx]
+    }
+    pub fn set_scent(&mut self, v: u8) {
+        let idx = self.universe.get_index(self.x, self.y);
+
+        self.universe.scents[idx] = v;
+    }
+    pub fn get_pressure(&mut self) -> u8 {
+        let idx = self.universe.get_index(self.x, self.y);
+
+        self.universe.pressures[idx]
+    }
 
     pub fn rand_int(&mut self, n: i32) -> i32 {
         self.universe.rng.gen_range(0..n)
@@ -194,8 +288,11 @@ impl Universe {
             for y in 0..self.height {
                 let idx = self.get_index(x, y);
                 self.cells[idx] = EMPTY_CELL;
+                self.velocities[idx] = ZERO_VELOCITY;
+                self.scents[idx] = 0;
             }
         }
+        self.projectiles.clear();
     }
     pub fn tick(&mut self) {
         // let mut next = self.cells.clone();
@@ -218,6 +315,17 @@ impl Universe {
                 )
             }
         }
+        self.update_pressure();
+        if self.structural {
+            self.update_structure();
+        }
+        if self.momentum {
+            self.update_momentum();
+        }
+        self.update_projectiles();
+        for s in self.scents.iter_mut() {
+            *s = s.saturating_sub(1);
+        }
         self.generation = self.generation.wrapping_add(1);
         for x in 0..self.width {
             let scanx = if self.generation % 2 == 0 {
@@ -269,6 +377,37 @@ impl Universe {
     pub fn burns(&self) -> *const Wind {
         self.burns.as_ptr()
     }
+
+    pub fn pressures(&self) -> *const u8 {
+        self.pressures.as_ptr()
+    }
+
+    pub fn velocities(&self) -> *const Velocity {
+        self.velocities.as_ptr()
+    }
+
+    pub fn scents(&self) -> *const u8 {
+        self.scents.as_ptr()
+    }
+
+    pub fn projectiles(&self) -> *const Projectile {
+        self.projectiles.as_ptr()
+    }
+
+    pub fn projectile_count(&self) -> usize {
+        self.projectiles.len()
+    }
+
+    pub fn set_structural(&mut self, structural: bool) {
+        self.structural = structural;
+    }
+
+    pub fn set_momentum(&mut self, momentum: bool) {
+        self.momentum = momentum;
+        for v in self.velocities.iter_mut() {
+            *v = ZERO_VELOCITY;
+        }
+    }
     pub fn paint(&mut self, x: i32, y: i32, size: i32, species: Species) {
         let size = size;
         let radius: f64 = (size as f64) / 2.0;
@@ -339,6 +478,10 @@ impl Universe {
                 density: 0,
             })
             .collect();
+        let pressures: Vec<u8> = (0..width * height).map(|_i| 0).collect();
+        let velocities: Vec<Velocity> = (0..width * height).map(|_i| ZERO_VELOCITY).collect();
+        let scents: Vec<u8> = (0..width * height).map(|_i| 0).collect();
+        let clusters: Vec<u32> = (0..width * height).map(|_i| 0).collect();
         let rng: SplitMix64 = SeedableRng::seed_from_u64(0x734f6b89de5f83cc);
         Universe {
             width,
@@ -347,6 +490,15 @@ impl Universe {
             undo_stack: VecDeque::with_capacity(50),
             burns,
             winds,
+            pressures,
+            structural: false,
+            clusters,
+            cluster_stamp: 0,
+            velocities,
+            momentum: false,
+            projectiles: Vec::new(),
+            scents,
+            reactions: reactions::index(),
             generation: 0,
             rng,
         }
@@ -369,8 +521,296 @@ impl Universe {
         return self.winds[i];
     }
 
+    // 静水压：用广度优先搜索找出每一块连通的液体，
+    // 液面是上方为空的液体细胞，每个液体细胞的压力等于它到这块液体最高液面的深度。
+    // 如果最低液面比最高液面低两格以上（例如 U 形管的两边），就把最高液面上的一格液体搬到最低液面的上方，
+    // 这样连通的容器最后会达到同一个液面高度。
+    fn update_pressure(&mut self) {
+        let mut visited = vec![false; self.cells.len()];
+        let mut body: Vec<(i32, i32)> = Vec::new();
+        let mut stack: Vec<(i32, i32)> = Vec::new();
+
+        for p in self.pressures.iter_mut() {
+            *p = 0;
+        }
+
+        for x in 0..self.width {
+            for y in 0..self.height {
+                let idx = self.get_index(x, y);
+                if visited[idx] || !self.cells[idx].species.is_hydrostatic() {
+                    continue;
+                }
+
+                body.clear();
+                stack.push((x, y));
+                visited[idx] = true;
+                while let Some((cx, cy)) = stack.pop() {
+                    body.push((cx, cy));
+                    for &(dx, dy) in [(1, 0), (-1, 0), (0, 1), (0, -1)].iter() {
+                        let (nx, ny) = (cx + dx, cy + dy);
+                        if nx < 0 || nx > self.width - 1 || ny < 0 || ny > self.height - 1 {
+                            continue;
+                        }
+                        let nidx = self.get_index(nx, ny);
+                        if !visited[nidx] && self.cells[nidx].species.is_hydrostatic() {
+                            visited[nidx] = true;
+                            stack.push((nx, ny));
+                        }
+                    }
+                }
+
+                // 最高液面和最低液面的位置，没有液面（完全封闭）时压力从这块液体的顶部算起
+                let (mut hx, mut hy) = (0, self.height);
+                let (mut lx, mut ly) = (0, -1);
+                let mut top = self.height;
+                for &(bx, by) in body.iter() {
+                    top = top.min(by);
+                    if by == 0 || self.get_cell(bx, by - 1).species != Species::Empty {
+                        continue;
+                    }
+                    if by < hy {
+                        hx = bx;
+                        hy = by;
+                    }
+                    if by > ly {
+                        lx = bx;
+                        ly = by;
+                    }
+                }
+
+                let level = if ly >= 0 { hy } else { top };
+                for &(bx, by) in body.iter() {
+                    let bidx = self.get_index(bx, by);
+                    self.pressures[bidx] = (by - level).clamp(0, 255) as u8;
+                }
+
+                if ly >= 0 && ly - hy >= 2 {
+                    let from = self.get_index(hx, hy);
+                    let to = self.get_index(lx, ly - 1);
+                    self.cells[to] = self.cells[from];
+                    self.cells[to].clock = self.generation.wrapping_add(1);
+                    self.cells[from] = EMPTY_CELL;
+                }
+            }
+        }
+    }
+
+    // 结构模式：从墙和贴着地面的固体出发做广度优先搜索，找出所有连在一起的结构固体。
+    // 剩下没有被找到的固体按连通块分组，如果整块下方都是空的（或者是它能沉下去的液体），整块就往下掉一格。
+    fn update_structure(&mut self) {
+        let mut supported = vec![false; self.cells.len()];
+        let mut stack: Vec<(i32, i32)> = Vec::new();
+
+        for x in 0..self.width {
+            for y in 0..self.height {
+                let idx = self.get_index(x, y);
+                let species = self.cells[idx].species;
+                let grounded = y == self.height - 1 && species.is_structural();
+                if species == Species::Wall || grounded {
+                    supported[idx] = true;
+                    stack.push((x, y));
+                }
+            }
+        }
+        while let Some((cx, cy)) = stack.pop() {
+            for &(dx, dy) in [(1, 0), (-1, 0), (0, 1), (0, -1)].iter() {
+                let (nx, ny) = (cx + dx, cy + dy);
+                if nx < 0 || nx > self.width - 1 || ny < 0 || ny > self.height - 1 {
+                    continue;
+                }
+                let nidx = self.get_index(nx, ny);
+                if !supported[nidx] && self.cells[nidx].species.is_structural() {
+                    supported[nidx] = true;
+                    stack.push((nx, ny));
+                }
+            }
+        }
+
+        let mut cluster: Vec<(i32, i32)> = Vec::new();
+        for x in 0..self.width {
+            for y in 0..self.height {
+                let idx = self.get_index(x, y);
+                if supported[idx] || !self.cells[idx].species.is_structural() {
+                    continue;
+                }
+
+                // 每一块用一个新的编号标记它的细胞，判断"下面是不是同一块"只要查一下编号，
+                // 编号数组跨帧复用，不用每块都清零
+                cluster.clear();
+                self.cluster_stamp = self.cluster_stamp.wrapping_add(1);
+                if self.cluster_stamp == 0 {
+                    self.clusters.iter_mut().for_each(|c| *c = 0);
+                    self.cluster_stamp = 1;
+                }
+                let stamp = self.cluster_stamp;
+                supported[idx] = true;
+                stack.push((x, y));
+                while let Some((cx, cy)) = stack.pop() {
+                    cluster.push((cx, cy));
+                    let cidx = self.get_index(cx, cy);
+                    self.clusters[cidx] = stamp;
+                    for &(dx, dy) in [(1, 0), (-1, 0), (0, 1), (0, -1)].iter() {
+                        let (nx, ny) = (cx + dx, cy + dy);
+                        if nx < 0 || nx > self.width - 1 || ny < 0 || ny > self.height - 1 {
+                            continue;
+                        }
+                        let nidx = self.get_index(nx, ny);
+                        if !supported[nidx] && self.cells[nidx].species.is_structural() {
+                            supported[nidx] = true;
+                            stack.push((nx, ny));
+                        }
+                    }
+                }
+
+                let can_fall = cluster.iter().all(|&(cx, cy)| {
+                    let cell = self.get_cell(cx, cy);
+                    let below = self.get_cell(cx, cy + 1);
+                    below.species == Species::Empty
+                        || self.clusters[self.get_index(cx, cy + 1)] == stamp
+                        || (below.species.is_liquid()
+                            && (cell.species.density() == 0
+                                || cell.species.density() > below.species.density()))
+                });
+                if !can_fall {
+                    continue;
+                }
+
+                // 从下往上移动，下方的空气或液体会被换到这一块的上面
+                cluster.sort_by_key(|&(_, cy)| -cy);
+                for &(cx, cy) in cluster.iter() {
+                    let from = self.get_index(cx, cy);
+                    let to = self.get_index(cx, cy + 1);
+                    self.cells.swap(from, to);
+                    // 标记新位置，免得同一帧里再被当成新的一块处理
+                    supported[to] = true;
+                    self.cells[to].clock = self.generation.wrapping_add(1);
+                }
+            }
+        }
+    }
+
+    // 动量模式：每帧给悬空的细胞加上重力，把风的横向分量加到速度上，再沿着速度方向一格一格地移动，
+    // 碰到障碍物时对应方向的速度清零。物种自己的更新逻辑每帧还会移动一格，这里只负责额外的位移。
+    // 高速落下的液体撞到东西时会溅开：竖直速度转成随机方向的横向速度，并向上弹起一点。
+    fn update_momentum(&mut self) {
+        let next = self.generation.wrapping_add(1);
+        for x in 0..self.width {
+            for y in 0..self.height {
+                let idx = self.get_index(x, y);
+                let cell = self.cells[idx];
+                if !cell.species.has_momentum() {
+                    self.velocities[idx] = ZERO_VELOCITY;
+                    continue;
+                }
+                if cell.clock == next {
+                    continue;
+                }
+
+                let mut vx = self.velocities[idx].vx as i32;
+                let mut vy = self.velocities[idx].vy as i32;
+                let below = if y < self.height - 1 {
+                    self.get_cell(x, y + 1).species
+                } else {
+                    Species::Wall
+                };
+
+                if below == Species::Empty {
+                    vy += 1;
+                } else if vy >= SPLASH_VELOCITY && (cell.species.is_liquid() || below.is_liquid()) {
+                    let dir = if self.rng.gen::<bool>() { 1 } else { -1 };
+                    vx = dir * vy;
+                    vy = -vy / 3;
+                } else if vy > 0 {
+                    vy = 0;
+                }
+
+                // 风（包括爆炸产生的气流）推着细胞横向加速
+                let wind = self.winds[idx];
+                vx += ((wind.dy as i32) - 126) / 32;
+                // 落地后有摩擦，横向速度慢慢衰减
+                if below != Species::Empty && vx != 0 {
+                    vx -= vx.signum();
+                }
+                vx = vx.clamp(-MAX_VELOCITY, MAX_VELOCITY);
+                vy = vy.clamp(-MAX_VELOCITY, MAX_VELOCITY);
+
+                let tx = vx / VELOCITY_SCALE;
+                let ty = vy / VELOCITY_SCALE;
+                let steps = tx.abs().max(ty.abs());
+                let (mut cx, mut cy) = (x, y);
+                for i in 1..=steps {
+                    let nx = x + tx * i / steps;
+                    let ny = y + ty * i / steps;
+                    if nx < 0 || nx > self.width - 1 || ny < 0 || ny > self.height - 1 {
+                        vx = 0;
+                        vy = 0;
+                        break;
+                    }
+                    if self.get_cell(nx, ny).species != Species::Empty {
+                        if nx != cx {
+                            vx = 0;
+                        }
+                        if ny != cy {
+                            vy = 0;
+                        }
+                        break;
+                    }
+                    let from = self.get_index(cx, cy);
+                    let to = self.get_index(nx, ny);
+                    self.cells.swap(from, to);
+                    self.velocities.swap(from, to);
+                    self.cells[to].clock = next;
+                    cx = nx;
+                    cy = ny;
+                }
+
+                let to = self.get_index(cx, cy);
+                self.velocities[to] = Velocity {
+                    vx: vx as i8,
+                    vy: vy as i8,
+                };
+            }
+        }
+    }
+
+    // 飞行粒子：每帧加上重力和所在位置的风，然后一小步一小步地沿速度方向前进。
+    // 碰到非空的格子时停在最后经过的空格里，变回普通的细胞；飞出画面的粒子直接消失。
+    fn update_projectiles(&mut self) {
+        let mut projectiles = std::mem::take(&mut self.projectiles);
+        projectiles.retain_mut(|p| {
+            let (x, y) = (p.x.floor() as i32, p.y.floor() as i32);
+            let wind = self.get_wind(x, y);
+            p.vx += ((wind.dy as f32) - 126.) * PROJECTILE_WIND;
+            p.vy += ((wind.dx as f32) - 126.) * PROJECTILE_WIND + PROJECTILE_GRAVITY;
+            p.vx = p.vx.clamp(-PROJECTILE_MAX_SPEED, PROJECTILE_MAX_SPEED);
+            p.vy = p.vy.clamp(-PROJECTILE_MAX_SPEED, PROJECTILE_MAX_SPEED);
+
+            let steps = p.vx.abs().max(p.vy.abs()).ceil().max(1.) as i32;
+            let (sx, sy) = (p.vx / steps as f32, p.vy / steps as f32);
+            for _ in 0..steps {
+                let (nx, ny) = ((p.x + sx).floor() as i32, (p.y + sy).floor() as i32);
+                if nx < 0 || nx > self.width - 1 || ny < 0 || ny > self.height - 1 {
+                    return false;
+                }
+                if self.get_cell(nx, ny).species != Species::Empty {
+                    let idx = self.get_index(p.x.floor() as i32, p.y.floor() as i32);
+                    if self.cells[idx].species == Species::Empty {
+                        self.cells[idx] = p.cell;
+                        self.cells[idx].clock = self.generation.wrapping_add(1);
+                    }
+                    return false;
+                }
+                p.x += sx;
+                p.y += sy;
+            }
+            true
+        });
+        projectiles.append(&mut self.projectiles);
+        self.projectiles = projectiles;
+    }
+
     fn blow_wind(cell: Cell, wind: Wind, mut api: SandApi) {
-        if cell.clock - api.universe.generation == 1 {
+        if cell.clock.wrapping_sub(api.universe.generation) == 1 {
             return;
         }
         if cell.species == Species::Empty {
@@ -379,34 +819,62 @@ impl Universe {
         let mut dx = 0;
         let mut dy = 0;
 
-        let threshold = match cell.species {
+        let mut threshold = match cell.species {
             Species::Empty => 500,
             Species::Wall => 500,
             Species::Cloner => 500,
+            Species::Glass => 500,
+            Species::Brick => 500,
+            // 蚯蚓的身体靠相邻的格子连在一起，不能被风吹散
+            Species::Worm => 500,
+            Species::WormBody => 500,
+            Species::Slime => 500,
+
+            Species::Metal => 90,
+            Species::Snail => 90,
 
             Species::Stone => 70,
+            Species::Concrete => 70,
+            Species::Clay => 70,
             Species::Wood => 70,
 
             Species::Plant => 60,
+            Species::Fish => 60,
+            Species::Kelp => 60,
+            Species::Grass => 60,
+            Species::Flower => 60,
+            Species::Vine => 60,
             Species::Lava => 60,
             Species::Ice => 60,
+            Species::Mud => 60,
 
             Species::Fungus => 54,
+            Species::PuffCap => 54,
 
             Species::Oil => 50,
+            Species::Honey => 50,
 
             // Intentionally left out and covered by the default case
             // Species::Water => 40,
             // Species::Acid => 40,
             Species::Seed => 35,
+            Species::Dirt => 35,
+            Species::Anthill => 35,
 
             Species::Sand => 30,
+            Species::Rust => 30,
+            Species::Cement => 30,
+            Species::WetSand => 40,
+            Species::Salt => 30,
+            Species::Base => 30,
             Species::Mite => 30,
+            Species::Ant => 30,
             Species::Rocket => 30,
 
             Species::Dust => 10,
             Species::Fire => 5,
             Species::Gas => 5,
+            Species::Spore => 2,
             /*
              Some hacked species values exist outside of the enum values.
              Making sure the default case is emitted allows "BELP" to have a defined wind threshold.
@@ -416,6 +884,16 @@ impl Universe {
             _ => 40,
         };
 
+        // 浮在液面上的木头、冰和植物会像下面的液体一样被风吹着漂走
+        let floor = api.get(0, 1).species;
+        if floor.is_liquid()
+            && !cell.species.is_liquid()
+            && cell.species.density() > 0
+            && cell.species.density() < floor.density()
+        {
+            threshold = threshold.min(40);
+        }
+
         let wx = (wind.dy as i32) - 126;
         let wy = (wind.dx as i32) - 126;
 
@@ -437,6 +915,7 @@ impl Universe {
                 && api.get(dx, -2).species == Species::Empty
                 && (cell.species == Species::Sand
                     || cell.species == Species::Water
+                    || cell.species == Species::Saltwater
                     || cell.species == Species::Lava
                     || cell.species == Species::Acid
                     || cell.species == Species::Mite
@@ -450,11 +929,56 @@ impl Universe {
             return;
         }
     }
-    fn update_cell(cell: Cell, api: SandApi) {
-        if cell.clock - api.universe.generation == 1 {
+    fn update_cell(cell: Cell, mut api: SandApi) {
+        if cell.clock.wrapping_sub(api.universe.generation) == 1 {
+            return;
+        }
+        if cell.species != Species::Empty && reactions::react(cell, &mut api) {
             return;
         }
 
         cell.update(api);
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn calm(universe: &mut Universe) {
+        for wind in universe.winds.iter_mut() {
+            wind.dx = 126;
+            wind.dy = 126;
+        }
+    }
+
+    #[test]
+    fn cut_tower_falls_as_one_piece() {
+        let mut universe = Universe::new(20, 30);
+        calm(&mut universe);
+        universe.set_structural(true);
+        for x in 8..12 {
+            for y in 5..30 {
+                universe.paint(x, y, 1, Species::Wood);
+            }
+            // 把塔的底部锯掉，只留最下面一行
+            for y in 20..29 {
+                universe.paint(x, y, 1, Species::Empty);
+            }
+        }
+
+        for step in 1..=9 {
+            universe.tick();
+            for x in 8..12 {
+                for y in 0..29 {
+                    let expected = if y >= 5 + step && y < 20 + step {
+                        Species::Wood
+                    } else {
+                        Species::Empty
+                    };
+                    assert_eq!(universe.get_cell(x, y).species, expected);
+                }
+            }
+        }
+    }
+}
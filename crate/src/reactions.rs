@@ -0,0 +1,707 @@
+use species::Species;
+use Cell;
+use SandApi;
+use EMPTY_CELL;
+
+// 反应表：把两个相邻物种之间的简单反应写成数据，而不是在 species.rs 里写分支。
+//
+// 每次 tick 更新一个细胞之前，会随机选一个相邻的细胞，在表里查找 (自己的物种, 邻居的物种) 对应的反应。
+// 找到并且条件满足时，以 1/chance 的概率发生反应：自己变成 a_to，邻居变成 b_to，然后这次更新结束。
+// 点燃也写在这里：火或岩浆只是把可燃物的 rb 设成燃烧倒计时，燃烧的过程（倒数、往外喷火、被水浇灭）仍然在各自的 update_* 函数里。
+// 表在 Universe::new 时按 a 的物种建好索引（见 index），没有任何反应的物种（例如墙）直接跳过。
+
+// 反应的另一方：某一个物种，或者满足条件的任何物种（例如酸能腐蚀的东西）。
+#[derive(Clone, Copy)]
+pub enum Partner {
+    Is(Species),
+    Any(fn(Species) -> bool),
+}
+
+// 反应之后细胞变成什么：
+//
+// Keep 保持不变；Become 变成另一个物种（保留 ra 作为颜色，rb 清零）；
+// BecomeWith 和 Become 一样，但 rb 设成给定的初始值（例如湿沙的湿度、混凝土的养护时间）；
+// Ignite 物种不变，只把 rb 设成燃烧倒计时；
+// Compute 由函数算出新细胞，参数依次是自己、对方、以及从自己指向对方的方向。
+#[derive(Clone, Copy)]
+pub enum Product {
+    Keep,
+    Become(Species),
+    BecomeWith(Species, u8),
+    Ignite(u8),
+    Compute(fn(Cell, Cell, i32, i32) -> Cell),
+}
+
+pub struct Reaction {
+    pub a: Species,
+    pub b: Partner,
+    pub chance: i32,
+    pub when: fn(Cell, Cell) -> bool,
+    pub a_to: Product,
+    pub b_to: Product,
+}
+
+fn always(_a: Cell, _b: Cell) -> bool {
+    true
+}
+
+// 只有还没烧起来（rb 为 0）的可燃物才会被点燃，正在烧的不会被重新点燃
+fn unlit(a: Cell, _b: Cell) -> bool {
+    a.rb == 0
+}
+
+// 没烧起来的油碰到刚开始烧（rb 在 2 到 19 之间）的油也会被引燃
+fn oil_catches(a: Cell, b: Cell) -> bool {
+    a.rb == 0 && b.rb > 1 && b.rb < 20
+}
+
+// 岩浆冷却成的石头颜色随方向略有不同
+fn cooled_stone(_lava: Cell, _water: Cell, dx: i32, dy: i32) -> Cell {
+    Cell {
+        species: Species::Stone,
+        ra: (150 + (dx + dy) * 10) as u8,
+        rb: 0,
+        clock: 0,
+    }
+}
+
+// 岩浆点燃的气体和粉尘
+fn lava_flame(_lava: Cell, _fuel: Cell, dx: i32, dy: i32) -> Cell {
+    Cell {
+        species: Species::Fire,
+        ra: (150 + (dx + dy) * 10) as u8,
+        rb: 0,
+        clock: 0,
+    }
+}
+
+// 草、马勃和花一碰到火就整格变成火焰，草烧得最旺
+fn grass_flame(_grass: Cell, _heat: Cell, _dx: i32, _dy: i32) -> Cell {
+    Cell {
+        species: Species::Fire,
+        ra: 120,
+        rb: 0,
+        clock: 0,
+    }
+}
+
+fn puff_cap_flame(_puff_cap: Cell, _heat: Cell, _dx: i32, _dy: i32) -> Cell {
+    Cell {
+        species: Species::Fire,
+        ra: 80,
+        rb: 0,
+        clock: 0,
+    }
+}
+
+fn flower_flame(_flower: Cell, _heat: Cell, _dx: i32, _dy: i32) -> Cell {
+    Cell {
+        species: Species::Fire,
+        ra: 40,
+        rb: 0,
+        clock: 0,
+    }
+}
+
+// 酸能直接溶解的物种：空气、墙、酸自身、玻璃、只能被慢慢啃掉的金属和砖，以及会和酸中和的碱以外都可以
+fn corrodible(species: Species) -> bool {
+    !matches!(
+        species,
+        Species::Empty
+            | Species::Wall
+            | Species::Acid
+            | Species::Glass
+            | Species::Metal
+            | Species::Base
+            | Species::Brick
+    )
+}
+
+// 酸吃掉一格东西并占据那里，自己的 ra 减 60，ra 不够就一起消失
+fn spent_acid(_food: Cell, acid: Cell, _dx: i32, _dy: i32) -> Cell {
+    if acid.ra < 140 {
+        EMPTY_CELL
+    } else {
+        Cell {
+            ra: acid.ra - 60,
+            ..acid
+        }
+    }
+}
+
+// 金属和砖不会被一口吃掉：每次只降低 2 点 ra（颜色变暗），降到 40 以下才溶解
+fn worn(solid: Cell, _acid: Cell, _dx: i32, _dy: i32) -> Cell {
+    if solid.ra < 40 {
+        EMPTY_CELL
+    } else {
+        Cell {
+            ra: solid.ra - 2,
+            ..solid
+        }
+    }
+}
+
+// 啃金属或砖时酸只消耗 5 点 ra
+fn nibbling_acid(acid: Cell, _solid: Cell, _dx: i32, _dy: i32) -> Cell {
+    if acid.ra < 85 {
+        EMPTY_CELL
+    } else {
+        Cell {
+            ra: acid.ra - 5,
+            ..acid
+        }
+    }
+}
+
+pub static REACTIONS: &[Reaction] = &[
+    // 岩浆遇水变成石头
+    Reaction {
+        a: Species::Lava,
+        b: Partner::Is(Species::Water),
+        chance: 1,
+        when: always,
+        a_to: Product::Compute(cooled_stone),
+        b_to: Product::Become(Species::Empty),
+    },
+    // 岩浆点燃气体和粉尘
+    Reaction {
+        a: Species::Lava,
+        b: Partner::Is(Species::Gas),
+        chance: 1,
+        when: always,
+        a_to: Product::Keep,
+        b_to: Product::Compute(lava_flame),
+    },
+    Reaction {
+        a: Species::Lava,
+        b: Partner::Is(Species::Dust),
+        chance: 1,
+        when: always,
+        a_to: Product::Keep,
+        b_to: Product::Compute(lava_flame),
+    },
+    // 火或岩浆点燃木头、植物、真菌、藤蔓和油，rb 是各自的燃烧时间
+    Reaction {
+        a: Species::Wood,
+        b: Partner::Is(Species::Fire),
+        chance: 1,
+        when: unlit,
+        a_to: Product::Ignite(90),
+        b_to: Product::Keep,
+    },
+    Reaction {
+        a: Species::Wood,
+        b: Partner::Is(Species::Lava),
+        chance: 1,
+        when: unlit,
+        a_to: Product::Ignite(90),
+        b_to: Product::Keep,
+    },
+    Reaction {
+        a: Species::Plant,
+        b: Partner::Is(Species::Fire),
+        chance: 1,
+        when: unlit,
+        a_to: Product::Ignite(20),
+        b_to: Product::Keep,
+    },
+    Reaction {
+        a: Species::Plant,
+        b: Partner::Is(Species::Lava),
+        chance: 1,
+        when: unlit,
+        a_to: Product::Ignite(20),
+        b_to: Product::Keep,
+    },
+    Reaction {
+        a: Species::Fungus,
+        b: Partner::Is(Species::Fire),
+        chance: 1,
+        when: unlit,
+        a_to: Product::Ignite(10),
+        b_to: Product::Keep,
+    },
+    Reaction {
+        a: Species::Fungus,
+        b: Partner::Is(Species::Lava),
+        chance: 1,
+        when: unlit,
+        a_to: Product::Ignite(10),
+        b_to: Product::Keep,
+    },
+    Reaction {
+        a: Species::Vine,
+        b: Partner::Is(Species::Fire),
+        chance: 1,
+        when: unlit,
+        a_to: Product::Ignite(20),
+        b_to: Product::Keep,
+    },
+    Reaction {
+        a: Species::Vine,
+        b: Partner::Is(Species::Lava),
+        chance: 1,
+        when: unlit,
+        a_to: Product::Ignite(20),
+        b_to: Product::Keep,
+    },
+    Reaction {
+        a: Species::Oil,
+        b: Partner::Is(Species::Fire),
+        chance: 1,
+        when: unlit,
+        a_to: Product::Ignite(50),
+        b_to: Product::Keep,
+    },
+    Reaction {
+        a: Species::Oil,
+        b: Partner::Is(Species::Lava),
+        chance: 1,
+        when: unlit,
+        a_to: Product::Ignite(50),
+        b_to: Product::Keep,
+    },
+    Reaction {
+        a: Species::Oil,
+        b: Partner::Is(Species::Oil),
+        chance: 1,
+        when: oil_catches,
+        a_to: Product::Ignite(50),
+        b_to: Product::Keep,
+    },
+    // 沙子被岩浆熔成玻璃，泡了水变成湿沙
+    Reaction {
+        a: Species::Sand,
+        b: Partner::Is(Species::Lava),
+        chance: 3,
+        when: always,
+        a_to: Product::Become(Species::Glass),
+        b_to: Product::Keep,
+    },
+    Reaction {
+        a: Species::Sand,
+        b: Partner::Is(Species::Water),
+        chance: 1,
+        when: always,
+        a_to: Product::BecomeWith(Species::WetSand, 120),
+        b_to: Product::Keep,
+    },
+    // 金属泡在水里慢慢生锈
+    Reaction {
+        a: Species::Metal,
+        b: Partner::Is(Species::Water),
+        chance: 200,
+        when: always,
+        a_to: Product::Become(Species::Rust),
+        b_to: Product::Keep,
+    },
+    // 水泥吸收一格水变成湿混凝土，rb 是养护倒计时
+    Reaction {
+        a: Species::Cement,
+        b: Partner::Is(Species::Water),
+        chance: 1,
+        when: always,
+        a_to: Product::BecomeWith(Species::Concrete, 200),
+        b_to: Product::Become(Species::Empty),
+    },
+    // 草、马勃和花碰到火或岩浆直接烧成火焰
+    Reaction {
+        a: Species::Grass,
+        b: Partner::Is(Species::Fire),
+        chance: 1,
+        when: always,
+        a_to: Product::Compute(grass_flame),
+        b_to: Product::Keep,
+    },
+    Reaction {
+        a: Species::Grass,
+        b: Partner::Is(Species::Lava),
+        chance: 1,
+        when: always,
+        a_to: Product::Compute(grass_flame),
+        b_to: Product::Keep,
+    },
+    Reaction {
+        a: Species::PuffCap,
+        b: Partner::Is(Species::Fire),
+        chance: 1,
+        when: always,
+        a_to: Product::Compute(puff_cap_flame),
+        b_to: Product::Keep,
+    },
+    Reaction {
+        a: Species::PuffCap,
+        b: Partner::Is(Species::Lava),
+        chance: 1,
+        when: always,
+        a_to: Product::Compute(puff_cap_flame),
+        b_to: Product::Keep,
+    },
+    Reaction {
+        a: Species::Flower,
+        b: Partner::Is(Species::Fire),
+        chance: 1,
+        when: always,
+        a_to: Product::Compute(flower_flame),
+        b_to: Product::Keep,
+    },
+    Reaction {
+        a: Species::Flower,
+        b: Partner::Is(Species::Lava),
+        chance: 1,
+        when: always,
+        a_to: Product::Compute(flower_flame),
+        b_to: Product::Keep,
+    },
+    // 冰遇到火或岩浆融化
+    Reaction {
+        a: Species::Ice,
+        b: Partner::Is(Species::Fire),
+        chance: 1,
+        when: always,
+        a_to: Product::Become(Species::Water),
+        b_to: Product::Keep,
+    },
+    Reaction {
+        a: Species::Ice,
+        b: Partner::Is(Species::Lava),
+        chance: 1,
+        when: always,
+        a_to: Product::Become(Species::Water),
+        b_to: Product::Keep,
+    },
+    // 冰让相邻的水结冰，但盐水不会结冰，反而让冰慢慢融化
+    Reaction {
+        a: Species::Ice,
+        b: Partner::Is(Species::Water),
+        chance: 14,
+        when: always,
+        a_to: Product::Keep,
+        b_to: Product::Become(Species::Ice),
+    },
+    Reaction {
+        a: Species::Ice,
+        b: Partner::Is(Species::Saltwater),
+        chance: 33,
+        when: always,
+        a_to: Product::Become(Species::Water),
+        b_to: Product::Keep,
+    },
+    // 盐溶解在水里，并且会融化冰
+    Reaction {
+        a: Species::Salt,
+        b: Partner::Is(Species::Water),
+        chance: 1,
+        when: always,
+        a_to: Product::Become(Species::Empty),
+        b_to: Product::Become(Species::Saltwater),
+    },
+    Reaction {
+        a: Species::Salt,
+        b: Partner::Is(Species::Ice),
+        chance: 5,
+        when: always,
+        a_to: Product::Keep,
+        b_to: Product::Become(Species::Water),
+    },
+    // 盐水遇热蒸发，留下盐的结晶
+    Reaction {
+        a: Species::Saltwater,
+        b: Partner::Is(Species::Fire),
+        chance: 1,
+        when: always,
+        a_to: Product::Become(Species::Salt),
+        b_to: Product::Keep,
+    },
+    Reaction {
+        a: Species::Saltwater,
+        b: Partner::Is(Species::Lava),
+        chance: 1,
+        when: always,
+        a_to: Product::Become(Species::Salt),
+        b_to: Product::Keep,
+    },
+    // 酸碱中和：生成盐和水，酸这一侧反应时还会放出气体
+    Reaction {
+        a: Species::Base,
+        b: Partner::Is(Species::Acid),
+        chance: 1,
+        when: always,
+        a_to: Product::Become(Species::Salt),
+        b_to: Product::Become(Species::Water),
+    },
+    Reaction {
+        a: Species::Acid,
+        b: Partner::Is(Species::Base),
+        chance: 1,
+        when: always,
+        a_to: Product::Become(Species::Gas),
+        b_to: Product::Become(Species::Salt),
+    },
+    // 酸慢慢啃金属和砖，砖比金属更耐酸
+    Reaction {
+        a: Species::Acid,
+        b: Partner::Is(Species::Metal),
+        chance: 4,
+        when: always,
+        a_to: Product::Compute(nibbling_acid),
+        b_to: Product::Compute(worn),
+    },
+    Reaction {
+        a: Species::Acid,
+        b: Partner::Is(Species::Brick),
+        chance: 8,
+        when: always,
+        a_to: Product::Compute(nibbling_acid),
+        b_to: Product::Compute(worn),
+    },
+    // 其他东西被酸直接溶解，酸占据那一格并变弱
+    Reaction {
+        a: Species::Acid,
+        b: Partner::Any(corrodible),
+        chance: 3,
+        when: always,
+        a_to: Product::Become(Species::Empty),
+        b_to: Product::Compute(spent_acid),
+    },
+];
+
+// 按 a 的物种给反应表建索引：index()[物种] 是这个物种作为 a 的所有反应在 REACTIONS 里的下标
+pub fn index() -> Vec<Vec<usize>> {
+    let mut by_species: Vec<Vec<usize>> = vec![Vec::new(); 256];
+    for (i, reaction) in REACTIONS.iter().enumerate() {
+        by_species[reaction.a as usize].push(i);
+    }
+    by_species
+}
+
+fn matches(partner: Partner, species: Species) -> bool {
+    match partner {
+        Partner::Is(b) => b == species,
+        Partner::Any(pred) => pred(species),
+    }
+}
+
+// own 是这一格原来的细胞，other 是反应的另一方，(dx, dy) 从这一格指向另一方
+fn apply(own: Cell, other: Cell, dx: i32, dy: i32, product: Product) -> Cell {
+    match product {
+        Product::Keep => own,
+        Product::Become(Species::Empty) => EMPTY_CELL,
+        Product::Become(species) => Cell {
+            species,
+            ra: own.ra,
+            rb: 0,
+            clock: 0,
+        },
+        Product::BecomeWith(species, rb) => Cell {
+            species,
+            ra: own.ra,
+            rb,
+            clock: 0,
+        },
+        Product::Ignite(rb) => Cell { rb, ..own },
+        Product::Compute(f) => f(own, other, dx, dy),
+    }
+}
+
+// 在反应表中查找当前细胞和一个随机邻居之间的反应。发生了反应时返回 true，调用者就不再执行这个细胞的 update。
+pub fn react(cell: Cell, api: &mut SandApi) -> bool {
+    let count = api.universe.reactions[cell.species as usize].len();
+    if count == 0 {
+        return false;
+    }
+    let (dx, dy) = api.rand_vec_8();
+    let nbr = api.get(dx, dy);
+
+    for k in 0..count {
+        let reaction = &REACTIONS[api.universe.reactions[cell.species as usize][k]];
+        if !matches(reaction.b, nbr.species) || !(reaction.when)(cell, nbr) {
+            continue;
+        }
+        if !api.once_in(reaction.chance) {
+            return false;
+        }
+        let a_to = apply(cell, nbr, dx, dy, reaction.a_to);
+        let b_to = apply(nbr, cell, -dx, -dy, reaction.b_to);
+        api.set(0, 0, a_to);
+        api.set(dx, dy, b_to);
+        return true;
+    }
+    false
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use Universe;
+
+    // 3x3 的小世界，中间是 center，周围一圈都是 around
+    fn surrounded(center: Species, around: Species) -> Universe {
+        let mut universe = Universe::new(3, 3);
+        for x in 0..3 {
+            for y in 0..3 {
+                universe.paint(x, y, 1, around);
+            }
+        }
+        universe.paint(1, 1, 1, Species::Empty);
+        universe.paint(1, 1, 1, center);
+        universe
+    }
+
+    fn set_center(universe: &mut Universe, ra: u8, rb: u8) {
+        let i = universe.get_index(1, 1);
+        universe.cells[i].ra = ra;
+        universe.cells[i].rb = rb;
+    }
+
+    fn react_once(universe: &mut Universe) -> bool {
+        let cell = universe.get_cell(1, 1);
+        let mut api = SandApi {
+            x: 1,
+            y: 1,
+            universe,
+        };
+        react(cell, &mut api)
+    }
+
+    // 反复尝试直到发生反应，返回 (中间的细胞, 周围所有细胞)
+    fn react_until(universe: &mut Universe) -> (Cell, Vec<Cell>) {
+        for _ in 0..1000 {
+            if react_once(universe) {
+                let around = (0..9)
+                    .filter(|&i| i != 4)
+                    .map(|i| universe.get_cell(i / 3, i % 3))
+                    .collect();
+                return (universe.get_cell(1, 1), around);
+            }
+        }
+        panic!("no reaction happened");
+    }
+
+    #[test]
+    fn every_reaction_is_indexed_once() {
+        let by_species = index();
+        let total: usize = by_species.iter().map(|r| r.len()).sum();
+        assert_eq!(total, REACTIONS.len());
+        for reaction in REACTIONS.iter() {
+            assert!(reaction.chance >= 1);
+        }
+    }
+
+    #[test]
+    fn species_without_reactions_skip_the_table() {
+        let mut universe = surrounded(Species::Wall, Species::Acid);
+        for _ in 0..100 {
+            assert!(!react_once(&mut universe));
+        }
+    }
+
+    #[test]
+    fn lava_cools_into_stone_with_varied_colour() {
+        let mut universe = surrounded(Species::Lava, Species::Water);
+        let (center, around) = react_until(&mut universe);
+        assert_eq!(center.species, Species::Stone);
+        assert!(center.ra >= 130 && center.ra <= 170);
+        let dried = around
+            .iter()
+            .filter(|c| c.species == Species::Empty)
+            .count();
+        assert_eq!(dried, 1);
+    }
+
+    #[test]
+    fn fire_ignites_unlit_wood() {
+        let mut universe = surrounded(Species::Wood, Species::Fire);
+        let (center, _) = react_until(&mut universe);
+        assert_eq!(center.species, Species::Wood);
+        assert_eq!(center.rb, 90);
+    }
+
+    #[test]
+    fn burning_wood_is_not_reignited() {
+        let mut universe = surrounded(Species::Wood, Species::Fire);
+        set_center(&mut universe, 100, 40);
+        for _ in 0..100 {
+            assert!(!react_once(&mut universe));
+        }
+    }
+
+    #[test]
+    fn burning_oil_spreads_to_unlit_oil() {
+        let mut universe = surrounded(Species::Oil, Species::Oil);
+        for i in 0..9 {
+            universe.cells[i].rb = 10;
+        }
+        set_center(&mut universe, 100, 0);
+        let (center, _) = react_until(&mut universe);
+        assert_eq!(center.rb, 50);
+    }
+
+    #[test]
+    fn acid_dissolves_sand_and_weakens() {
+        let mut universe = surrounded(Species::Acid, Species::Sand);
+        set_center(&mut universe, 200, 0);
+        let (center, around) = react_until(&mut universe);
+        assert_eq!(center.species, Species::Empty);
+        let acid: Vec<&Cell> = around
+            .iter()
+            .filter(|c| c.species == Species::Acid)
+            .collect();
+        assert_eq!(acid.len(), 1);
+        assert_eq!(acid[0].ra, 140);
+    }
+
+    #[test]
+    fn acid_wears_metal_down_slowly() {
+        let mut universe = surrounded(Species::Acid, Species::Metal);
+        for i in 0..9 {
+            universe.cells[i].ra = 100;
+        }
+        set_center(&mut universe, 200, 0);
+        let (center, around) = react_until(&mut universe);
+        assert_eq!(center.species, Species::Acid);
+        assert_eq!(center.ra, 195);
+        let worn = around.iter().filter(|c| c.ra == 98).count();
+        assert_eq!(worn, 1);
+    }
+
+    #[test]
+    fn sand_soaks_up_water() {
+        let mut universe = surrounded(Species::Sand, Species::Water);
+        let (center, _) = react_until(&mut universe);
+        assert_eq!(center.species, Species::WetSand);
+        assert_eq!(center.rb, 120);
+    }
+
+    #[test]
+    fn cement_sets_into_concrete() {
+        let mut universe = surrounded(Species::Cement, Species::Water);
+        let (center, around) = react_until(&mut universe);
+        assert_eq!(center.species, Species::Concrete);
+        assert_eq!(center.rb, 200);
+        let absorbed = around
+            .iter()
+            .filter(|c| c.species == Species::Empty)
+            .count();
+        assert_eq!(absorbed, 1);
+    }
+
+    #[test]
+    fn grass_flashes_into_fire() {
+        let mut universe = surrounded(Species::Grass, Species::Lava);
+        let (center, _) = react_until(&mut universe);
+        assert_eq!(center.species, Species::Fire);
+        assert_eq!(center.ra, 120);
+    }
+
+    #[test]
+    fn acid_and_base_neutralise() {
+        let mut universe = surrounded(Species::Acid, Species::Base);
+        let (center, around) = react_until(&mut universe);
+        assert_eq!(center.species, Species::Gas);
+        assert_eq!(
+            around.iter().filter(|c| c.species == Species::Salt).count(),
+            1
+        );
+    }
+}
@@ -35,9 +35,122 @@ pub enum Species {
     Dust = 14,
     Oil = 16,
     Rocket = 17,
+    Glass = 20,
+    Metal = 21,
+    Rust = 22,
+    Cement = 23,
+    Concrete = 24,
+    Dirt = 25,
+    Mud = 26,
+    WetSand = 27,
+    Salt = 28,
+    Saltwater = 29,
+    Base = 30,
+    Clay = 31,
+    Brick = 32,
+    Honey = 33,
+    PuffCap = 34,
+    Spore = 35,
+    Worm = 36,
+    WormBody = 37,
+    Ant = 38,
+    Anthill = 39,
+    Snail = 40,
+    Slime = 41,
+    Fish = 42,
+    Kelp = 43,
+    Grass = 44,
+    Flower = 45,
+    Vine = 46,
 }
 
 impl Species {
+    // 液体的黏度：0 表示像水一样自由流动，数值越大，液体横向流动得越少。
+    // 黏度为 0 的水每次最多能横向滑两格，有黏度的液体每 (黏度 + 1) 次更新才横向移动一格（见 sideways_reach）。
+    pub fn viscosity(&self) -> i32 {
+        match self {
+            Species::Oil => 1,
+            Species::Mud => 3,
+            Species::Lava => 4,
+            Species::Honey => 8,
+            _ => 0,
+        }
+    }
+
+    // 所有液体：参与静水压的液体，加上岩浆、蜂蜜、泥浆这些黏稠的液体。
+    // 用于判断浮力、溅射和结构模式下整块固体能不能沉进去。
+    pub fn is_liquid(&self) -> bool {
+        self.is_hydrostatic() || matches!(self, Species::Lava | Species::Honey | Species::Mud)
+    }
+
+    // 密度（水为 100），用于计算浮力。只有液体和能浮起来的固体（木头、植物、冰）有密度，其他物种为 0。
+    pub fn density(&self) -> i32 {
+        match self {
+            Species::Wood => 60,
+            Species::Plant => 70,
+            Species::Oil => 90,
+            Species::Ice => 92,
+            Species::Water => 100,
+            Species::Acid => 110,
+            Species::Saltwater => 110,
+            Species::Honey => 140,
+            Species::Mud => 170,
+            Species::Lava => 250,
+            _ => 0,
+        }
+    }
+
+    // 颗粒物的休止角（度），决定了它能堆出多陡的坡，只有用 tumble 滑动的颗粒物才有，其他物种为 0。
+    pub fn repose(&self) -> i32 {
+        match self {
+            Species::Dust => 25,
+            Species::Seed => 35,
+            Species::Sand => 45,
+            Species::WetSand => 60,
+            Species::Stone => 65,
+            _ => 0,
+        }
+    }
+
+    // 动量模式下会保留速度的粉末和流动性好的液体：下落时会加速，被风或爆炸推动后会继续横着飞一段。
+    pub fn has_momentum(&self) -> bool {
+        matches!(
+            self,
+            Species::Sand
+                | Species::Dust
+                | Species::Salt
+                | Species::Base
+                | Species::Cement
+                | Species::Rust
+                | Species::Dirt
+                | Species::Seed
+                | Species::Lava
+        ) || self.is_hydrostatic()
+    }
+
+    // 结构模式下参与受力计算的固体：没有连接到地面或墙上时会整块掉下来。
+    pub fn is_structural(&self) -> bool {
+        matches!(
+            self,
+            Species::Wood
+                | Species::Stone
+                | Species::Ice
+                | Species::Metal
+                | Species::Glass
+                | Species::Brick
+                | Species::Concrete
+        )
+    }
+
+    // 参与静水压计算的液体：连通的这些液体会流到同一个液面高度。
+    // 岩浆、蜂蜜、泥浆这些黏稠的液体不参与。
+    pub fn is_hydrostatic(&self) -> bool {
+        matches!(
+            self,
+            Species::Water | Species::Saltwater | Species::Oil | Species::Acid
+        )
+    }
+
     // Species::update 方法是一个分发器，根据不同的物种类型调用不同的更新函数。每个物种的行为是由其对应的 update_* 方法决定的。
     pub fn update(&self, cell: Cell, api: SandApi) {
         match self {
@@ -63,10 +176,135 @@ impl Species {
             Species::Oil => update_oil(cell, api),
             Species::Fungus => update_fungus(cell, api),
             Species::Seed => update_seed(cell, api),
+            Species::Glass => {}
+            Species::Metal => update_metal(cell, api),
+            Species::Rust => update_rust(cell, api),
+            Species::Cement => update_cement(cell, api),
+            Species::Concrete => update_concrete(cell, api),
+            Species::Dirt => update_dirt(cell, api),
+            Species::Mud => update_mud(cell, api),
+            Species::WetSand => update_wet_sand(cell, api),
+            Species::Salt => update_salt(cell, api),
+            Species::Saltwater => update_saltwater(cell, api),
+            Species::Base => update_base(cell, api),
+            Species::Brick => {}
+            Species::Clay => update_clay(cell, api),
+            Species::Honey => update_honey(cell, api),
+            Species::PuffCap => update_puff_cap(cell, api),
+            Species::Spore => update_spore(cell, api),
+            Species::Worm => update_worm(cell, api),
+            Species::WormBody => update_worm_body(cell, api),
+            Species::Ant => update_ant(cell, api),
+            Species::Anthill => update_anthill(cell, api),
+            Species::Snail => update_snail(cell, api),
+            Species::Slime => update_slime(cell, api),
+            Species::Fish => update_fish(cell, api),
+            Species::Kelp => update_kelp(cell, api),
+            Species::Grass => update_grass(cell, api),
+            Species::Flower => update_flower(cell, api),
+            Species::Vine => update_vine(cell, api),
             // Species::X => update_x(cell, api),
         }
     }
 }
+// 根据物种的黏度决定这次更新液体最多能横向流几格：
+// 黏度为 0 的液体每次都能流，最多两格；有黏度的液体每 (黏度 + 1) 次更新才有一次能流一格，其余时候是 0。
+fn sideways_reach(species: Species, api: &mut SandApi) -> i32 {
+    let viscosity = species.viscosity();
+    if viscosity == 0 {
+        2
+    } else if api.once_in(viscosity + 1) {
+        1
+    } else {
+        0
+    }
+}
+
+// 液体沿 dx 方向横着流，最多流 reach 格，只能穿过空格，停在能到达的最远的那一格上。流动了返回 true。
+fn flow_sideways(cell: Cell, dx: i32, reach: i32, api: &mut SandApi) -> bool {
+    let mut dist = 0;
+    while dist < reach && api.get(dx * (dist + 1), 0).species == Species::Empty {
+        dist += 1;
+    }
+    if dist == 0 {
+        return false;
+    }
+    api.set(0, 0, EMPTY_CELL);
+    api.set(dx * dist, 0, cell);
+    true
+}
+
+// 压力足够大的液体旁边如果有空位，会从洞口喷出去，一次最多喷两格。
+fn spurt(cell: Cell, api: &mut SandApi) -> bool {
+    if api.get_pressure() < 8 {
+        return false;
+    }
+    let dx = api.rand_dir_2();
+    if api.get(dx, 0).species != Species::Empty {
+        return false;
+    }
+    api.set(0, 0, EMPTY_CELL);
+    if api.get(dx * 2, 0).species == Species::Empty {
+        api.set(dx * 2, 0, cell);
+    } else {
+        api.set(dx, 0, cell);
+    }
+    true
+}
+
+// 浮力：比液体轻的固体（木头、冰、植物碎片）如果上方是更重的液体，就和它交换位置，慢慢浮到液面上。
+// 只有挂在墙或结构固体伸出来的檐下面时才算被固定住：斜上方是墙或结构固体、而正侧面是空的或液体。
+// 容器的侧壁不算，因为侧壁在正侧面也是固体，贴着池壁的冰块照样能浮上来。
+fn float(cell: Cell, api: &mut SandApi) -> bool {
+    let above = api.get(0, -1).species;
+    if !above.is_liquid() || above.density() <= cell.species.density() {
+        return false;
+    }
+    for &dx in [-1, 1].iter() {
+        let ledge = api.get(dx, -1).species;
+        let side = api.get(dx, 0).species;
+        if (ledge == Species::Wall || ledge.is_structural())
+            && (side == Species::Empty || side.is_liquid())
+        {
+            return false;
+        }
+    }
+    let above = api.get(0, -1);
+    api.set(0, 0, above);
+    api.set(0, -1, cell);
+    true
+}
+
+// 把休止角导出给 JS，信息页会显示每种颗粒物的休止角。
+#[wasm_bindgen]
+pub fn angle_of_repose(species: Species) -> i32 {
+    species.repose()
+}
+
+// 颗粒物正下方被挡住时，按休止角决定要不要往 dx 那一侧滑：
+// 休止角小于 45 度的（灰尘）在两格外有落差时会先横着挪一格，堆出来的坡大约是 1:2，又平又散；
+// 45 度的（沙子）只要斜下方是空的就滑下去；
+// 大于 45 度的（石头）要斜下方连续两格都是空的才会滑，堆成大约 2:1 的陡坡。
+pub fn tumble(cell: Cell, dx: i32, api: &mut SandApi) -> bool {
+    let angle = cell.species.repose();
+    if api.get(dx, 1).species == Species::Empty
+        && (angle <= 45 || api.get(dx, 2).species == Species::Empty)
+    {
+        api.set(0, 0, EMPTY_CELL);
+        api.set(dx, 1, cell);
+        return true;
+    }
+    if angle < 45
+        && api.get(dx, 0).species == Species::Empty
+        && api.get(dx * 2, 1).species == Species::Empty
+    {
+        api.set(0, 0, EMPTY_CELL);
+        api.set(dx, 0, cell);
+        return true;
+    }
+    false
+}
+
 // update_sand 方法处理沙子的行为。沙子会根据周围环境进行下落：
 //
 // 如果下方是空的，沙子会下落。
@@ -74,20 +312,52 @@ impl Species {
 // 如果周围有水、气体、油或酸，沙子也会交换位置。
 
 // 沙子的更新逻辑是根据其周围的细胞状态来决定的。
+// 沙子碰到岩浆会熔化成玻璃、碰到水会变成湿沙（WetSand），这两个反应写在 reactions.rs 的反应表里。
+// 靠近火焰时 rb 作为热量累积，超过阈值后同样变成玻璃，离开热源后热量逐渐散去。
 pub fn update_sand(cell: Cell, mut api: SandApi) {
+    let (hx, hy) = api.rand_vec_8();
+    let heat_src = api.get(hx, hy).species;
+    if cell.rb > 120 {
+        api.set(
+            0,
+            0,
+            Cell {
+                species: Species::Glass,
+                ra: cell.ra,
+                rb: 0,
+                clock: 0,
+            },
+        );
+        return;
+    }
+    let cell = if heat_src == Species::Fire
+        || heat_src == Species::Lava
+        || (heat_src == Species::Metal && api.get(hx, hy).rb > 100)
+    {
+        Cell {
+            rb: cell.rb.saturating_add(10),
+            ..cell
+        }
+    } else {
+        Cell {
+            rb: cell.rb.saturating_sub(1),
+            ..cell
+        }
+    };
+
     let dx = api.rand_dir_2();
 
     let nbr = api.get(0, 1);
     if nbr.species == Species::Empty {
         api.set(0, 0, EMPTY_CELL);
         api.set(0, 1, cell);
-    } else if api.get(dx, 1).species == Species::Empty {
-        api.set(0, 0, EMPTY_CELL);
-        api.set(dx, 1, cell);
+    } else if tumble(cell, dx, &mut api) {
+        // 已经按休止角滑到旁边了
     } else if nbr.species == Species::Water
         || nbr.species == Species::Gas
         || nbr.species == Species::Oil
         || nbr.species == Species::Acid
+        || nbr.species == Species::Saltwater
     {
         api.set(0, 0, nbr);
         api.set(0, 1, cell);
@@ -96,6 +366,75 @@ pub fn update_sand(cell: Cell, mut api: SandApi) {
     }
 }
 
+// update_wet_sand 方法处理湿沙的行为，rb 表示湿度：
+//
+// 碰到水时湿度恢复到 120；湿度会沿着相邻的沙子向上、向两侧传递（毛细作用），每传一格少 30，所以只能传几格。
+// 没有水补充时湿度慢慢下降，降到 0 时变回干沙；靠近火焰或岩浆干得更快。刚画出来的湿沙 rb 是 0，按刚泡过水的 120 算。
+// 湿沙会结块：休止角是 60 度，比干沙陡，用 tumble 往斜下方滑，所以能堆出更陡的沙堆。
+pub fn update_wet_sand(cell: Cell, mut api: SandApi) {
+    let (nx, ny) = api.rand_vec_8();
+    let nbr = api.get(nx, ny);
+
+    let mut rb = if cell.rb == 0 { 120 } else { cell.rb };
+    if nbr.species == Species::Water {
+        rb = 120;
+    } else if nbr.species == Species::Fire || nbr.species == Species::Lava {
+        rb = rb.saturating_sub(20);
+    } else if api.once_in(4) {
+        rb = rb.saturating_sub(1);
+    }
+
+    if rb == 0 {
+        api.set(
+            0,
+            0,
+            Cell {
+                species: Species::Sand,
+                ra: cell.ra,
+                rb: 0,
+                clock: 0,
+            },
+        );
+        return;
+    }
+
+    // 毛细作用
+    if rb > 40
+        && ny <= 0
+        && (nbr.species == Species::Sand || (nbr.species == Species::WetSand && nbr.rb < rb - 30))
+    {
+        api.set(
+            nx,
+            ny,
+            Cell {
+                species: Species::WetSand,
+                ra: nbr.ra,
+                rb: rb - 30,
+                clock: 0,
+            },
+        );
+    }
+
+    let cell = Cell { rb, ..cell };
+    let dx = api.rand_dir_2();
+    let below = api.get(0, 1);
+    if below.species == Species::Empty {
+        api.set(0, 0, EMPTY_CELL);
+        api.set(0, 1, cell);
+    } else if tumble(cell, dx, &mut api) {
+        // 已经按休止角滑到旁边了
+    } else if below.species == Species::Water
+        || below.species == Species::Gas
+        || below.species == Species::Oil
+        || below.species == Species::Acid
+    {
+        api.set(0, 0, below);
+        api.set(0, 1, cell);
+    } else {
+        api.set(0, 0, cell);
+    }
+}
+
 // update_dust 方法描述了尘土的行为：
 //
 // 如果流体的压力大于 120，尘土会变为火，并生成一个风流体（Wind）。
@@ -131,10 +470,7 @@ pub fn update_dust(cell: Cell, mut api: SandApi) {
     } else if nbr.species == Species::Water {
         api.set(0, 0, nbr);
         api.set(0, 1, cell);
-    } else if api.get(dx, 1).species == Species::Empty {
-        api.set(0, 0, EMPTY_CELL);
-        api.set(dx, 1, cell);
-    } else {
+    } else if !tumble(cell, dx, &mut api) {
         api.set(0, 0, cell);
     }
 }
@@ -166,13 +502,17 @@ pub fn update_stone(cell: Cell, mut api: SandApi) {
 
     let nbr = api.get(0, 1);
     let nbr_species = nbr.species;
+    let dx = api.rand_dir_2();
     if nbr_species == Species::Empty {
         api.set(0, 0, EMPTY_CELL);
         api.set(0, 1, cell);
+    } else if tumble(cell, dx, &mut api) {
+        // 斜下方有两格落差时滚下去，堆成陡峭的石堆
     } else if nbr_species == Species::Water
         || nbr_species == Species::Gas
         || nbr_species == Species::Oil
         || nbr_species == Species::Acid
+        || nbr_species == Species::Saltwater
     {
         api.set(0, 0, nbr);
         api.set(0, 1, cell);
@@ -184,6 +524,9 @@ pub fn update_stone(cell: Cell, mut api: SandApi) {
 // 在一个细胞自动机的模拟中处理水的行为，可能是用来模拟沙盒游戏或者物理引擎中的流体行为。它通过不同的条件和随机行为来操控当前水的细胞及其邻近的细胞。
 // cell: 当前的水细胞。  api: 一个引用 SandApi 的对象，提供了随机数生成和操作邻近细胞的方法。
 pub fn update_water(cell: Cell, mut api: SandApi) {
+    if spurt(cell, &mut api) {
+        return;
+    }
     let mut dx = api.rand_dir();  // 随机方向
     let below = api.get(0, 1);    // 获取下方细胞
     let dx1 = api.get(dx, 1);     // 获取斜下方细胞
@@ -224,12 +567,14 @@ pub fn update_water(cell: Cell, mut api: SandApi) {
     }
 
 
+    // 水的黏度为 0，每次最多横向滑两格
+    let reach = sideways_reach(Species::Water, &mut api);
     let left = cell.ra % 2 == 0;  // 判断当前水是否在左侧（基于 ra）
     dx = if left { 1 } else { -1 };  // 根据 ra 确定方向
     let dx0 = api.get(dx, 0);  // 获取水流方向上的细胞
     let dxd = api.get(dx * 2, 0);  // 获取更远的细胞
 
-    if dx0.species == Species::Empty && dxd.species == Species::Empty {
+    if reach >= 2 && dx0.species == Species::Empty && dxd.species == Species::Empty {
         // scoot double
         // 双重滑动：
         //  如果当前方向和更远的位置都为空，水会移动到更远的空位置，模拟水流的扩展。
@@ -259,7 +604,7 @@ pub fn update_water(cell: Cell, mut api: SandApi) {
                 )
             }
         }
-    } else if dx0.species == Species::Empty || dx0.species == Species::Oil {
+    } else if reach >= 1 && (dx0.species == Species::Empty || dx0.species == Species::Oil) {
         // 当前水流方向上的邻居是否为空（Species::Empty）或者含有油   如果是空的或者是油，水就可以流到该位置。
         // 模拟水流在碰到空细胞或油时的行为，并尝试使水与周围的水细胞发生交互，特别是在它们的 ra
         api.set(0, 0, dx0);  // 将当前位置设置为 dx0（可能为空或者油）
@@ -354,20 +699,7 @@ pub fn update_oil(cell: Cell, mut api: SandApi) {
 
     // 1 火和熔岩与油的交互：
     //
-    // 如果油的 rb 为 0，且周围有火或熔岩，油会变成新的油，rb 设置为 50，这可能意味着油被加热或改变了粘度。
-    // 如果油遇到另一个油，且该油的 rb 值在 1 到 20 之间，油的 rb 会变为 50，这可能代表两种油的混合或者油的状态发生了改变。
-    if rb == 0 && nbr.species == Species::Fire
-        || nbr.species == Species::Lava
-        || (nbr.species == Species::Oil && nbr.rb > 1 && nbr.rb < 20)
-    {
-        // 如果符合条件，将油变为新的油，且设置 rb 为 50，代表油变得更加粘稠或具有特定特性
-        new_cell = Cell {
-            species: Species::Oil,
-            ra: cell.ra,
-            rb: 50,  // 可能代表油的粘稠度增加
-            clock: 0,
-        };
-    }
+    // 油碰到火、熔岩或刚烧起来的油时 rb 变成 50 开始燃烧，这个反应写在 reactions.rs 的反应表里。
     // 2 油的流动和粘度变化：
     //
     // 当油的 rb 值大于 1 时，油的 rb 值会递减，表示油的粘度逐渐变小，变得更容易流动。
@@ -438,7 +770,11 @@ pub fn update_oil(cell: Cell, mut api: SandApi) {
     // 如果油下方或周围的邻居是空的，油会向这些空白位置流动。油的流动遵循从当前位置（0, 0）向下、斜下、左下、右下等方向寻找空位置的顺序。
     // 如果所有周围位置都不是空的，油会停留在当前位置。
 
-    // 油的移动逻辑：如果下方或其它相邻位置是空的，油会流到该位置
+    // 油的移动逻辑：如果下方或其它相邻位置是空的，油会流到该位置；横向流动受黏度限制
+    let reach = sideways_reach(Species::Oil, &mut api);
+    if spurt(new_cell, &mut api) {
+        return;
+    }
     if api.get(0, 1).species == Species::Empty {
         api.set(0, 0, EMPTY_CELL);  // 清空当前位置
         api.set(0, 1, new_cell);  // 将油放置到下方
@@ -448,12 +784,10 @@ pub fn update_oil(cell: Cell, mut api: SandApi) {
     } else if api.get(-dx, 1).species == Species::Empty {
         api.set(0, 0, EMPTY_CELL);  // 清空当前位置
         api.set(-dx, 1, new_cell);  // 将油放置到反方向的下方
-    } else if api.get(dx, 0).species == Species::Empty {
-        api.set(0, 0, EMPTY_CELL);  // 清空当前位置
-        api.set(dx, 0, new_cell);  // 将油放置到水平方向
-    } else if api.get(-dx, 0).species == Species::Empty {
-        api.set(0, 0, EMPTY_CELL);  // 清空当前位置
-        api.set(-dx, 0, new_cell);  // 将油放置到反方向的水平方向
+    } else if flow_sideways(new_cell, dx, reach, &mut api) {
+        // 流到了水平方向
+    } else if flow_sideways(new_cell, -dx, reach, &mut api) {
+        // 流到了反方向的水平方向
     } else {
         // 如果没有空位置，保持当前位置不变
         api.set(0, 0, new_cell);
@@ -766,6 +1100,17 @@ pub fn update_rocket(cell: Cell, mut api: SandApi) {
                     ..cell
                 },
             );
+            // 尾部喷出的火星
+            if api.once_in(3) {
+                let vx = (api.rand_dir() - dx) as f32;
+                let spark = Cell {
+                    species: Species::Fire,
+                    ra: 40 + api.rand_int(40) as u8,
+                    rb: 0,
+                    clock: 0,
+                };
+                api.launch(0, 0, vx, -dy as f32, spark);
+            }
         } else {
             //fizzle
             api.set(0, 0, EMPTY_CELL);
@@ -829,12 +1174,30 @@ pub fn update_fire(cell: Cell, mut api: SandApi) {
             pressure: 80,
             density: 40,
         });
+        // 爆炸会把旁边松散的粉末炸飞成碎片，或者溅出火星
+        let (sx, sy) = api.rand_vec_8();
+        let debris = api.get(sx, sy);
+        if debris.species.has_momentum() && !debris.species.is_liquid() {
+            api.set(sx, sy, EMPTY_CELL);
+            api.launch(sx, sy, sx as f32 * 2.0, sy as f32 * 2.0 - 1.0, debris);
+        } else if api.once_in(4) {
+            let spark = Cell {
+                species: Species::Fire,
+                ra: 60,
+                rb: 0,
+                clock: 0,
+            };
+            api.launch(dx, dy, dx as f32 * 2.0, dy as f32 * 2.0 - 1.0, spark);
+        }
     }
     // 5. 火焰与水或空白区域的交互
     // 如果火焰的强度 ra 小于 5，或者扩散到的地方是水 (Species::Water)，则火焰会被熄灭（设置为空白细胞 EMPTY_CELL）。
     // 如果扩散到的地方是空白 (Species::Empty)，则将当前火焰置为空白，并将降解后的火焰放置到新的位置。
     // 如果扩散到的地方不是空白且也不是水，则将火焰的降解状态放置在当前位置。
-    if ra < 5 || api.get(dx, dy).species == Species::Water {
+    if ra < 5
+        || api.get(dx, dy).species == Species::Water
+        || api.get(dx, dy).species == Species::Saltwater
+    {
         api.set(0, 0, EMPTY_CELL);
     } else if api.get(dx, dy).species == Species::Empty {
         api.set(0, 0, EMPTY_CELL);
@@ -857,57 +1220,35 @@ pub fn update_lava(cell: Cell, mut api: SandApi) {
     });
 
     // 2. 随机选择一个相邻格子并检查其物质类型
-    // 这行代码使用 api.rand_vec() 生成一个随机的方向 (dx, dy)，用来选择一个相邻格子进行操作。
-    let (dx, dy) = api.rand_vec();
+    // 这行代码使用 api.rand_vec() 生成一个随机的方向，岩浆流动时只用到横向的 dx。
+    let (dx, _) = api.rand_vec();
 
     // 3. 与气体或灰尘交互
-    // 如果随机选择的格子是气体（Species::Gas）或灰尘（Species::Dust），则在该格子中生成一个火（Species::Fire）。
-    // 火的“活跃度”（ra）由 (150 + (dx + dy) * 10) 计算得到，rb 和 clock 则为 0。
-    if api.get(dx, dy).species == Species::Gas || api.get(dx, dy).species == Species::Dust {
-        api.set(
-            dx,
-            dy,
-            Cell {
-                species: Species::Fire,
-                ra: (150 + (dx + dy) * 10) as u8,
-                rb: 0,
-                clock: 0,
-            },
-        );
-    }
-
     // 4. 与水的交互
-    //  如果随机选择的格子是水（Species::Water），则岩浆与水发生反应，岩浆变成石头（Species::Stone），并将石头放置在当前格子。
-    // 同时，水被移除，设置为 EMPTY_CELL。
-    //
-    let sample = api.get(dx, dy);
-    if sample.species == Species::Water {
-        api.set(
-            0,
-            0,
-            Cell {
-                species: Species::Stone,
-                ra: (150 + (dx + dy) * 10) as u8,
-                rb: 0,
-                clock: 0,
-            },
-        );
-        api.set(dx, dy, EMPTY_CELL);
+    //  岩浆点燃气体和灰尘、碰到水变成石头，这些反应写在 reactions.rs 的反应表里。
 
-    //     5. 岩浆的移动
+    // 5. 岩浆的移动
     // 接下来，岩浆尝试向周围的空白格子（Species::Empty）移动。如果周围的格子是空的，它会向该格子移动，否则保持当前位置。
     // 检查当前格子（0, 0）上下左右（0, 1、dx, 1、dx, 0）是否为空（Species::Empty）。
     // 如果某个方向的格子为空，则岩浆会向该方向移动。
     // 如果没有空格子可以移动，则岩浆保持在原位置。
-    } else if api.get(0, 1).species == Species::Empty {
+    // 岩浆的黏度比水高，所以斜向和横向的流动都要先通过黏度检查，流得比水慢得多。
+    // 岩浆表面偶尔会溅起一团熔岩，在空中飞一段再落下来
+    if api.get(0, -1).species == Species::Empty && api.once_in(600) {
+        let vx = api.rand_dir() as f32 * 0.6;
+        api.set(0, 0, EMPTY_CELL);
+        api.launch(0, -1, vx, -2.0, cell);
+        return;
+    }
+    let reach = sideways_reach(Species::Lava, &mut api);
+    if api.get(0, 1).species == Species::Empty {
         api.set(0, 0, EMPTY_CELL);
         api.set(0, 1, cell);
-    } else if api.get(dx, 1).species == Species::Empty {
+    } else if reach > 0 && api.get(dx, 1).species == Species::Empty {
         api.set(0, 0, EMPTY_CELL);
         api.set(dx, 1, cell);
-    } else if api.get(dx, 0).species == Species::Empty {
-        api.set(0, 0, EMPTY_CELL);
-        api.set(dx, 0, cell);
+    } else if flow_sideways(cell, dx, reach, &mut api) {
+        // 横着流开
     } else {
         api.set(0, 0, cell);
     }
@@ -915,7 +1256,10 @@ pub fn update_lava(cell: Cell, mut api: SandApi) {
 
 // 模拟木材在沙盒模拟环境中的行为。木材的行为包括与火、熔岩、水等物质的互动，以及根据条件改变状态（如变为火或变为空）。
 pub fn update_wood(cell: Cell, mut api: SandApi) {
-    //
+    // 0. 木头比水和油轻，没有固定住的木头会浮起来
+    if float(cell, &mut api) {
+        return;
+    }
     let rb = cell.rb;
 
     let (dx, dy) = api.rand_vec();
@@ -923,20 +1267,7 @@ pub fn update_wood(cell: Cell, mut api: SandApi) {
     let nbr_species = api.get(dx, dy).species;
 
     // 1. 初始化与火或熔岩交互
-    // 这段代码首先判断木材（Wood）是否处于初始状态（rb == 0）。如果是并且它周围的格子是火（Fire）或熔岩（Lava），则木材将变为状态 Wood，并且将其 rb 设置为 90。
-    // ra 和 clock 由原始木材的属性继承
-    if rb == 0 && nbr_species == Species::Fire || nbr_species == Species::Lava {
-        api.set(
-            0,
-            0,
-            Cell {
-                species: Species::Wood,
-                ra: cell.ra,
-                rb: 90,
-                clock: 0,
-            },
-        );
-    }
+    // 没烧起来的木材碰到火或熔岩时 rb 变成 90 开始燃烧，这个反应写在 reactions.rs 的反应表里。
 
     // 2. 如果木材的 rb 大于 1，则进行如下操作
     // 如果木材的 rb 大于 1，木材的 rb 减 1，并保持木材的其他属性（species 和 ra）不变。
@@ -1004,11 +1335,12 @@ pub fn update_wood(cell: Cell, mut api: SandApi) {
     }
 }
 // 你的代码用于模拟冰（Ice）的行为，主要涉及冰与火、岩浆、水等物质的互动。代码的逻辑比较清晰
+// 冰与火、岩浆、水、盐水之间的反应都写在 reactions.rs 的反应表里，这里只处理压力。
+// 冰比水轻，没有固定住的冰块会浮在水面上。
 pub fn update_ice(cell: Cell, mut api: SandApi) {
-    let (dx, dy) = api.rand_vec();
-
-    let i = api.rand_int(100);
-
+    if float(cell, &mut api) {
+        return;
+    }
     let fluid = api.get_fluid();
 
     // // 如果流体压力大于120且有一定概率，冰会变成水
@@ -1023,34 +1355,6 @@ pub fn update_ice(cell: Cell, mut api: SandApi) {
                 clock: 0,
             },
         );
-        return;
-    }
-
-    let nbr_species = api.get(dx, dy).species;
-    // // 如果邻居是火或岩浆，冰会变成水
-    if nbr_species == Species::Fire || nbr_species == Species::Lava {
-        api.set(
-            0,
-            0,
-            Cell {
-                species: Species::Water,
-                ra: cell.ra,
-                rb: cell.rb,
-                clock: 0,
-            },
-        );
-    } else if nbr_species == Species::Water && i < 7 {
-        // 如果邻居是水且随机条件成立，冰会变成冰块
-        api.set(
-            dx,
-            dy,
-            Cell {
-                species: Species::Ice,
-                ra: cell.ra,
-                rb: cell.rb,
-                clock: 0,
-            },
-        );
     }
 }
 
@@ -1059,27 +1363,24 @@ pub fn update_ice(cell: Cell, mut api: SandApi) {
 
 // 该函数描述了植物（Plant）的生长和互动行为，主要操作包括扩散、繁殖、腐蚀、与其他物质互动等。
 pub fn update_plant(cell: Cell, mut api: SandApi) {
+    // 0. 脱落的植物碎片（周围没有其他植物）会浮到水面上
+    if api.get(0, -1).species.is_liquid()
+        && api.get(-1, 0).species != Species::Plant
+        && api.get(1, 0).species != Species::Plant
+        && api.get(0, 1).species != Species::Plant
+        && float(cell, &mut api)
+    {
+        return;
+    }
 
     // 植物与火焰或岩浆的交互：
-    // 如果植物周围有火焰（Fire）或岩浆（Lava），并且植物的rb为0，它会变成一个新的植物，ra保持不变，rb设置为20（表示一些生长状态）。
+    // 没烧起来的植物碰到火焰或岩浆时 rb 变成 20 开始燃烧，这个反应写在 reactions.rs 的反应表里。
     let rb = cell.rb;
 
     let mut i = api.rand_int(100);
     let (dx, dy) = api.rand_vec();
 
     let nbr_species = api.get(dx, dy).species;
-    if rb == 0 && nbr_species == Species::Fire || nbr_species == Species::Lava {
-        api.set(
-            0,
-            0,
-            Cell {
-                species: Species::Plant,
-                ra: cell.ra,
-                rb: 20,
-                clock: 0,
-            },
-        );
-    }
 
     // 2 与木材的交互：
     // 如果植物旁边有木材（Wood），它会随机选择一个邻居位置（dx, dy），并将植物繁殖到这个空白位置。
@@ -1125,6 +1426,35 @@ pub fn update_plant(cell: Cell, mut api: SandApi) {
         );
         api.set(-dx, dy, EMPTY_CELL);
     }
+    // 与湿润泥土的交互：
+    //
+    // 如果植物旁边是湿润的泥土或泥浆，植物会吸收一部分湿度，在随机的空白邻居处长出新的植物。
+    if (nbr_species == Species::Dirt || nbr_species == Species::Mud) && api.once_in(5) {
+        let soil = api.get(dx, dy);
+        let (gx, gy) = api.rand_vec();
+        if soil.rb > 40 && api.get(gx, gy).species == Species::Empty {
+            api.set(
+                dx,
+                dy,
+                Cell {
+                    rb: soil.rb - 20,
+                    ..soil
+                },
+            );
+            let drift = (i % 15) - 7;
+            let newra = (cell.ra as i32 + drift) as u8;
+            api.set(
+                gx,
+                gy,
+                Cell {
+                    species: Species::Plant,
+                    ra: newra,
+                    rb: 0,
+                    clock: 0,
+                },
+            );
+        }
+    }
     // 4 植物的生命周期：
     //
     // 如果植物的rb大于1，它会减少rb并产生火焰（Fire）或者将植物的ra调整为50，如果附近是水。
@@ -1202,6 +1532,16 @@ pub fn update_plant(cell: Cell, mut api: SandApi) {
     }
 }
 
+// 发芽时的 rb：会长茎（ra > 60）的种子把 ra 当作花色记在 rb 里，沿着茎一直带到顶上；
+// 不长茎的种子和原来一样用随机的 rb，作为花瓣的颜色。
+fn sprout_rb(ra: u8, api: &mut SandApi) -> u8 {
+    if ra > 60 {
+        ra
+    } else {
+        (api.rand_int(253) + 1) as u8
+    }
+}
+
 // 描述了种子（Seed）的行为逻辑。它实现了种子从空中掉落、与周围物质的互动以及生长和扩展等行为。
 pub fn update_seed(cell: Cell, mut api: SandApi) {
     // 1 火焰与岩浆的处理：
@@ -1242,18 +1582,24 @@ pub fn update_seed(cell: Cell, mut api: SandApi) {
             || nbr_species_below == Species::Plant
             || nbr_species_below == Species::Fungus
         {
-            let rb = (api.rand_int(253) + 1) as u8;
+            let rb = sprout_rb(cell.ra, &mut api);
             api.set(0, 0, Cell { rb, ..cell });
             return;
         }
+        // 泥土比沙子更适合发芽：种子的 ra 会提高，所以茎长得更高。
+        if nbr_species_below == Species::Dirt || nbr_species_below == Species::Mud {
+            let ra = cell.ra.saturating_add(20);
+            let rb = sprout_rb(ra, &mut api);
+            api.set(0, 0, Cell { ra, rb, ..cell });
+            return;
+        }
 
         let nbr = api.get(0, 1);
         if nbr.species == Species::Empty {
             api.set(0, 0, EMPTY_CELL);
             api.set(0, 1, cell);
-        } else if api.get(dxf, 1).species == Species::Empty {
-            api.set(0, 0, EMPTY_CELL);
-            api.set(dxf, 1, cell);
+        } else if tumble(cell, dxf, &mut api) {
+            // 种子是圆的，比沙子更容易滚开
         } else if nbr.species == Species::Water
             || nbr.species == Species::Gas
             || nbr.species == Species::Oil
@@ -1281,7 +1627,24 @@ pub fn update_seed(cell: Cell, mut api: SandApi) {
                     && api.get(-1, -1).species != Species::Plant
                 {
                     let ra = (ra as i32 - api.rand_int(10)) as u8;
-                    api.set(dxr, -1, Cell { ra, ..cell });
+                    let top = if ra > 60 {
+                        Cell { ra, ..cell }
+                    } else if api.once_in(3) {
+                        // 4 开花：
+                        //
+                        // 茎长到头时，三分之一的种子在顶上开出一朵花（Flower），花色就是一路带上来的 rb。
+                        Cell {
+                            species: Species::Flower,
+                            ra: rb,
+                            rb: 0,
+                            clock: 0,
+                        }
+                    } else {
+                        // 其余的种子换回随机的 rb，之后照常长出花瓣
+                        let rb = (api.rand_int(253) + 1) as u8;
+                        Cell { ra, rb, ..cell }
+                    };
+                    api.set(dxr, -1, top);
                     let ra2 = 80 + api.rand_int(30) as u8;
                     api.set(
                         0,
@@ -1356,20 +1719,7 @@ pub fn update_fungus(cell: Cell, mut api: SandApi) {
 
     // 2 火焰与岩浆扩散：
     //
-    // 如果当前 rb == 0 且相邻的单元格是火焰（Fire）或岩浆（Lava），则创建一个新的真菌并将其放置在当前位置。
-    // api.set(0, 0, Cell {...})：将当前位置的细胞替换为新的真菌细胞。
-    if rb == 0 && nbr_species == Species::Fire || nbr_species == Species::Lava {
-        api.set(
-            0,
-            0,
-            Cell {
-                species: Species::Fungus,
-                ra: cell.ra,
-                rb: 10,
-                clock: 0,
-            },
-        );
-    }
+    // 没烧起来的真菌碰到火焰或岩浆时 rb 变成 10 开始燃烧，这个反应写在 reactions.rs 的反应表里。
 
     // 3 随机扩散：
     //
@@ -1500,6 +1850,120 @@ pub fn update_fungus(cell: Cell, mut api: SandApi) {
             }
         }
     }
+
+    // 7 长出马勃：
+    //
+    // 菌丝上方是空的时候，偶尔会冒出一个马勃（PuffCap），马勃成熟后会把孢子散到风里。
+    if api.get(0, -1).species == Species::Empty
+        && api.get(-1, -1).species != Species::PuffCap
+        && api.get(1, -1).species != Species::PuffCap
+        && api.once_in(3000)
+    {
+        api.set(
+            0,
+            -1,
+            Cell {
+                species: Species::PuffCap,
+                ra: cell.ra,
+                rb: 0,
+                clock: 0,
+            },
+        );
+    }
+}
+
+// 马勃（PuffCap）是长在菌丝上的子实体，rb 是成熟度：
+//
+// 成熟度慢慢涨到 150 以后，马勃开始往上方释放孢子，每放出一个孢子成熟度加 10，
+// 放完（涨到 250）以后萎缩成普通的菌丝。碰到火或岩浆会烧起来（见 reactions.rs），下面悬空时会掉下去。
+pub fn update_puff_cap(cell: Cell, mut api: SandApi) {
+    if api.get(0, 1).species == Species::Empty {
+        api.set(0, 0, EMPTY_CELL);
+        api.set(0, 1, cell);
+        return;
+    }
+
+    let mut rb = cell.rb;
+    if rb < 150 {
+        if api.once_in(3) {
+            rb += 1;
+        }
+    } else {
+        let sx = api.rand_dir();
+        if api.get(sx, -1).species == Species::Empty && api.once_in(6) {
+            api.set(
+                sx,
+                -1,
+                Cell {
+                    species: Species::Spore,
+                    ra: cell.ra,
+                    rb: 0,
+                    clock: 0,
+                },
+            );
+            rb = rb.saturating_add(10);
+        }
+    }
+
+    if rb >= 250 {
+        api.set(
+            0,
+            0,
+            Cell {
+                species: Species::Fungus,
+                ra: cell.ra,
+                rb: 0,
+                clock: 0,
+            },
+        );
+        return;
+    }
+    api.set(0, 0, Cell { rb, ..cell });
+}
+
+// 孢子（Spore）非常轻，几乎任何一点风都能把它吹走（blow_wind 里的阈值只有 2）：
+//
+// 没有风的时候慢慢往下飘；碰到木头或植物就在原地萌发成新的菌丝；碰到火或岩浆就烧掉。
+// rb 是孢子的年龄，飘太久还没找到能萌发的地方就会消失。
+pub fn update_spore(cell: Cell, mut api: SandApi) {
+    let (dx, dy) = api.rand_vec_8();
+    let nbr_species = api.get(dx, dy).species;
+    if nbr_species == Species::Fire || nbr_species == Species::Lava || cell.rb == 250 {
+        api.set(0, 0, EMPTY_CELL);
+        return;
+    }
+    if nbr_species == Species::Wood || nbr_species == Species::Plant {
+        api.set(
+            0,
+            0,
+            Cell {
+                species: Species::Fungus,
+                ra: cell.ra,
+                rb: 0,
+                clock: 0,
+            },
+        );
+        return;
+    }
+
+    let aged = if api.once_in(2) {
+        Cell {
+            rb: cell.rb + 1,
+            ..cell
+        }
+    } else {
+        cell
+    };
+    let fx = api.rand_dir();
+    if api.once_in(3) && api.get(fx, 1).species == Species::Empty {
+        api.set(0, 0, EMPTY_CELL);
+        api.set(fx, 1, aged);
+    } else if api.once_in(2) && api.get(fx, 0).species == Species::Empty {
+        api.set(0, 0, EMPTY_CELL);
+        api.set(fx, 0, aged);
+    } else {
+        api.set(0, 0, aged);
+    }
 }
 
 // 这段代码实现了酸（Acid）物质的行为逻辑，根据不同的条件酸会向周围扩散、腐蚀或退化。代码通过检查周围单元格的物质种类来决定酸的移动或变更。
@@ -1507,166 +1971,579 @@ pub fn update_acid(cell: Cell, mut api: SandApi) {
     // 1.方向控制：
     //
     // let dx = api.rand_dir(); 随机决定一个方向，dx 代表水平方向的移动量（可以是 1 或 -1）
+    if spurt(cell, &mut api) {
+        return;
+    }
     let dx = api.rand_dir();
 
-    //2. 酸的退化：
+    //2. 酸的退化和腐蚀：
     //
-    // let ra = cell.ra; 获取当前酸的腐蚀程度。
-    // let mut degraded = cell.clone(); 创建酸的副本。
-    // degraded.ra = ra - 60; 酸的腐蚀程度减少 60，表示酸的退化。
-    // 如果酸的腐蚀程度小于 80（degraded.ra < 80），则酸会消失（设置为空单元格 EMPTY_CELL）。
-    let ra = cell.ra;
-    let mut degraded = cell.clone();
-    degraded.ra = ra - 60;
-    // i = api.rand_int(100);
-    if degraded.ra < 80 {
-        degraded = EMPTY_CELL;
-    }
+    // 酸溶解相邻的东西、慢慢啃金属和砖、和碱中和，这些反应都写在 reactions.rs 的反应表里。
+    // 每溶解一格酸的 ra 减少 60，ra 不够时酸也一起消失。
 
     // 3.酸的扩散：
     //
-    // 通过检查四个方向（上、右、左、下）的相邻单元格，酸决定是否扩散到这些空白区域或腐蚀周围的物质。
+    // 通过检查下、右、左三个方向的相邻单元格，酸决定是否扩散到这些空白区域。
     // 优先向下移动（api.get(0, 1)），如果下方为空，则酸向下扩散。
-    // 如果下方不是空单元格，尝试向右（api.get(dx, 0)）或向左（api.get(-dx, 0)）移动。
-    // 如果四个方向都被阻挡（例如遇到墙壁 Species::Wall 或酸 Species::Acid），酸会检查是否能向上（api.get(0, -1)) 移动。
+    // 如果下方不是空单元格，尝试向右（dx）或向左（-dx）横着流，酸的黏度为 0，每次最多流两格。
+    // 三个方向都被挡住时留在原地。
     // 向下
+    let reach = sideways_reach(Species::Acid, &mut api);
     if api.get(0, 1).species == Species::Empty {
         api.set(0, 0, EMPTY_CELL);
         api.set(0, 1, cell);
-    } else if api.get(dx, 0).species == Species::Empty {
+    } else if flow_sideways(cell, dx, reach, &mut api) {
         // 向右
-        api.set(0, 0, EMPTY_CELL);
-        api.set(dx, 0, cell);
-    } else if api.get(-dx, 0).species == Species::Empty {
+    } else if flow_sideways(cell, -dx, reach, &mut api) {
         // 向左
-        api.set(0, 0, EMPTY_CELL);
-        api.set(-dx, 0, cell);
     } else {
-        // 向上
-        if api.get(0, 1).species != Species::Wall && api.get(0, 1).species != Species::Acid {
-            api.set(0, 0, EMPTY_CELL);
-            api.set(0, 1, degraded);
-        } else if api.get(dx, 0).species != Species::Wall && api.get(dx, 0).species != Species::Acid
-        {
-            api.set(0, 0, EMPTY_CELL);
-            api.set(dx, 0, degraded);
-        } else if api.get(-dx, 0).species != Species::Wall
-            && api.get(-dx, 0).species != Species::Acid
-        {
-            api.set(0, 0, EMPTY_CELL);
-            api.set(-dx, 0, degraded);
-        } else if api.get(0, -1).species != Species::Wall
-            && api.get(0, -1).species != Species::Acid
-            && api.get(0, -1).species != Species::Empty
-        {
-            // 4 酸的腐蚀行为：
-            //
-            // 如果酸能够移动到空单元格，它会将自己放到新位置，并将当前单元格清空。
-            // 如果周围不是空单元格，酸会腐蚀（退化）周围的物质。如果周围的物质是墙壁或酸，酸不会继续腐蚀。否则，它会把腐蚀后的酸放到该位置。
-            api.set(0, 0, EMPTY_CELL);
-            api.set(0, -1, degraded);
-        } else {
-            // 5 回退行为：
-            //
-            // 如果酸没有找到可以移动或腐蚀的地方，它会保持在当前位置。
-            api.set(0, 0, cell);
-        }
+        api.set(0, 0, cell);
     }
 }
 
-pub fn update_mite(cell: Cell, mut api: SandApi) {
-    // 1：初始设置：
-    // 生成一个随机整数，dx 和 dy 代表螨虫的移动方向。
-    // 根据 cell.ra 和 cell.rb 的值来调整 dx 和 dy，这决定了螨虫的移动方向。
-    let mut i = api.rand_int(100);
-    let mut dx = 0;
+// 金属（Metal）是静止的固体：
+//
+// rb 表示金属的温度。接触火焰或岩浆时 rb 升到最高，之后每次更新把热量和随机一个相邻的金属平均，所以热量沿金属传递得很快；
+// 温度超过 100 时会点燃相邻的可燃物、融化冰。
+// ra 表示金属的完整程度，会被酸慢慢降低（见 reactions.rs）。
+// 金属接触水时会缓慢生锈，变成铁锈（Rust）粉末（见 reactions.rs）。
+pub fn update_metal(cell: Cell, mut api: SandApi) {
+    let (dx, dy) = api.rand_vec_8();
+    let nbr = api.get(dx, dy);
 
-    //2 方向控制：
-    //
-    // 如果 cell.ra < 20，螨虫向左移动（dx = (cell.ra as i32) - 1）。
-    // 如果 cell.rb > 10，螨虫向上移动（dy = -1），否则向下移动（dy = 1）。
-    if cell.ra < 20 {
-        dx = (cell.ra as i32) - 1;
+    let mut rb = cell.rb.saturating_sub(1);
+    if nbr.species == Species::Fire || nbr.species == Species::Lava {
+        rb = 250;
+    } else if nbr.species == Species::Metal {
+        // 热传导：两块金属的温度取平均
+        let avg = ((rb as u16 + nbr.rb as u16) / 2) as u8;
+        rb = avg;
+        api.set(dx, dy, Cell { rb: avg, ..nbr });
+    } else if rb > 100 {
+        if nbr.species == Species::Ice {
+            api.set(
+                dx,
+                dy,
+                Cell {
+                    species: Species::Water,
+                    ..nbr
+                },
+            );
+        } else if (nbr.species == Species::Wood
+            || nbr.species == Species::Plant
+            || nbr.species == Species::Oil
+            || nbr.species == Species::Gas
+            || nbr.species == Species::Dust
+            || nbr.species == Species::Fungus
+            || nbr.species == Species::Seed)
+            && api.once_in(5)
+        {
+            let ra = 60 + api.rand_int(60) as u8;
+            api.set(
+                dx,
+                dy,
+                Cell {
+                    species: Species::Fire,
+                    ra,
+                    rb: 0,
+                    clock: 0,
+                },
+            );
+        } else if nbr.species == Species::Water && api.once_in(10) {
+            // 热金属把水烧干
+            rb = rb.saturating_sub(20);
+            api.set(dx, dy, EMPTY_CELL);
+        }
     }
-    let mut dy = 1;
-    let mut mite = cell.clone();
 
+    api.set(0, 0, Cell { rb, ..cell });
+}
 
-    if cell.rb > 10 {
-        // /
-        mite.rb = mite.rb.saturating_sub(1);
-        dy = -1;
-    } else if cell.rb > 1 {
-        // \
-        mite.rb = mite.rb.saturating_sub(1);
+// 铁锈（Rust）是易碎的粉末，像沙子一样下落，在水中下沉。
+pub fn update_rust(cell: Cell, mut api: SandApi) {
+    let dx = api.rand_dir_2();
+
+    let nbr = api.get(0, 1);
+    if nbr.species == Species::Empty {
+        api.set(0, 0, EMPTY_CELL);
+        api.set(0, 1, cell);
+    } else if api.get(dx, 1).species == Species::Empty {
+        api.set(0, 0, EMPTY_CELL);
+        api.set(dx, 1, cell);
+    } else if nbr.species == Species::Water
+        || nbr.species == Species::Gas
+        || nbr.species == Species::Oil
+        || nbr.species == Species::Acid
+    {
+        api.set(0, 0, nbr);
+        api.set(0, 1, cell);
     } else {
-        // |
-        dx = 0;
+        api.set(0, 0, cell);
     }
-    //3 与邻居的互动：
-    //
-    // nbr = api.get(dx, dy)：获取螨虫将要移动到的目标位置的单元格。
-    // 随机决定 sx 和 sy，来采样周围的单元格。
-    let nbr = api.get(dx, dy);
-
-    let sx = (i % 3) - 1;
-    i = api.rand_int(1000);
-    let sy = (i % 3) - 1;
-    let sample = api.get(sx, sy).species;
+}
 
-    // 4与特定物质的互动：
-    //
-    // 如果采样的物质是火（Fire）、岩浆（Lava）、水（Water）或油（Oil），螨虫会消失（设置为空单元格 EMPTY_CELL）。
+// 水泥（Cement）是像沙子一样流动的粉末。
+// 碰到水时吸收掉这格水，变成湿混凝土（rb 为养护倒计时），这个反应写在 reactions.rs 的反应表里。
+pub fn update_cement(cell: Cell, mut api: SandApi) {
+    let dx = api.rand_dir_2();
 
-    if sample == Species::Fire
-        || sample == Species::Lava
-        || sample == Species::Water
-        || sample == Species::Oil
-    {
+    let nbr = api.get(0, 1);
+    if nbr.species == Species::Empty {
         api.set(0, 0, EMPTY_CELL);
-        return;
-    }
-    // // 如果采样的是植物类物质（Plant、Wood、Seed），并且随机值 i > 800，螨虫会移动到该位置。
-    if (sample == Species::Plant || sample == Species::Wood || sample == Species::Seed) && i > 800 {
+        api.set(0, 1, cell);
+    } else if api.get(dx, 1).species == Species::Empty {
         api.set(0, 0, EMPTY_CELL);
-        api.set(sx, sy, cell);
+        api.set(dx, 1, cell);
+    } else if nbr.species == Species::Water
+        || nbr.species == Species::Gas
+        || nbr.species == Species::Oil
+        || nbr.species == Species::Acid
+    {
+        api.set(0, 0, nbr);
+        api.set(0, 1, cell);
+    } else {
+        api.set(0, 0, cell);
+    }
+}
 
+// 混凝土（Concrete）：
+//
+// rb > 0 表示还没凝固的湿混凝土，会像沙子一样下落坍塌，并把相邻的沙子、石头作为骨料拌进来（骨料的 rb 减半，所以不会无限扩散）。
+// 每次更新 rb 减 1，减到 0 后凝固成不会下落的静止固体，不像石头那样会塌。
+pub fn update_concrete(cell: Cell, mut api: SandApi) {
+    let rb = cell.rb;
+    if rb == 0 {
         return;
     }
 
-    // 5处理尘土：
-    //
-    // 如果采样的物质是尘土（Dust），螨虫会根据随机概率决定是否消失或者停留
-    if sample == Species::Dust {
-        api.set(sx, sy, if i > 800 { cell } else { EMPTY_CELL });
+    let (ax, ay) = api.rand_vec_8();
+    let aggregate = api.get(ax, ay);
+    if (aggregate.species == Species::Sand || aggregate.species == Species::Stone)
+        && rb > 20
+        && api.once_in(10)
+    {
+        api.set(
+            ax,
+            ay,
+            Cell {
+                species: Species::Concrete,
+                ra: aggregate.ra,
+                rb: rb / 2,
+                clock: 0,
+            },
+        );
     }
-    // 6 螨虫的移动：
-    //
-    // 如果目标位置是空的（Species::Empty），螨虫会移动到那里。
+
+    let cell = Cell { rb: rb - 1, ..cell };
+    let dx = api.rand_dir_2();
+    let nbr = api.get(0, 1);
     if nbr.species == Species::Empty {
         api.set(0, 0, EMPTY_CELL);
-        api.set(dx, dy, mite);
-    } else if dy == 1 && i > 800 {
-        // 如果周围被其它螨虫阻塞，螨虫可能会卡住或者改变方向。
-
-        i = api.rand_int(100);
-        let mut ndx = (i % 3) - 1;
-        if i < 6 {
-            //switch direction
-            ndx = dx;
-        }
+        api.set(0, 1, cell);
+    } else if api.get(dx, 1).species == Species::Empty {
+        api.set(0, 0, EMPTY_CELL);
+        api.set(dx, 1, cell);
+    } else if nbr.species == Species::Water
+        || nbr.species == Species::Gas
+        || nbr.species == Species::Oil
+    {
+        api.set(0, 0, nbr);
+        api.set(0, 1, cell);
+    } else {
+        api.set(0, 0, cell);
+    }
+}
 
-        mite.ra = (1 + ndx) as u8;
-        mite.rb = 10 + (i % 10) as u8; //hop height
+// 泥土（Dirt）是像沙子一样下落的粉末，rb 表示湿度：
+//
+// 吸收相邻的水，每格水增加 40 点湿度；湿度会在相邻泥土之间平均，也会慢慢蒸发，遇到火或岩浆时立刻烤干。
+// 湿度超过 200 时泥土饱和，变成泥浆（Mud）。
+pub fn update_dirt(cell: Cell, mut api: SandApi) {
+    let (nx, ny) = api.rand_vec_8();
+    let nbr = api.get(nx, ny);
+
+    let mut rb = cell.rb;
+    if api.once_in(20) {
+        rb = rb.saturating_sub(1);
+    }
+    if nbr.species == Species::Water && rb < 220 {
+        api.set(nx, ny, EMPTY_CELL);
+        rb = rb.saturating_add(40);
+    } else if nbr.species == Species::Fire || nbr.species == Species::Lava {
+        rb = 0;
+    } else if nbr.species == Species::Dirt {
+        let avg = ((rb as u16 + nbr.rb as u16) / 2) as u8;
+        rb = avg;
+        api.set(nx, ny, Cell { rb: avg, ..nbr });
+    }
 
-        api.set(0, 0, mite);
-    } else {
-        // 如果周围是冰块，螨虫会尝试在冰面上移动或者爬升。
-        if api.get(-1, 0).species == Species::Mite
-            && api.get(1, 0).species == Species::Mite
-            && api.get(0, -1).species == Species::Mite
-        {
-            api.set(0, 0, EMPTY_CELL);
+    if rb > 200 {
+        api.set(
+            0,
+            0,
+            Cell {
+                species: Species::Mud,
+                ra: cell.ra,
+                rb,
+                clock: 0,
+            },
+        );
+        return;
+    }
+
+    let cell = Cell { rb, ..cell };
+    let dx = api.rand_dir_2();
+    let below = api.get(0, 1);
+    if below.species == Species::Empty {
+        api.set(0, 0, EMPTY_CELL);
+        api.set(0, 1, cell);
+    } else if api.get(dx, 1).species == Species::Empty {
+        api.set(0, 0, EMPTY_CELL);
+        api.set(dx, 1, cell);
+    } else if below.species == Species::Water
+        || below.species == Species::Gas
+        || below.species == Species::Oil
+        || below.species == Species::Acid
+    {
+        api.set(0, 0, below);
+        api.set(0, 1, cell);
+    } else {
+        api.set(0, 0, cell);
+    }
+}
+
+// 泥浆（Mud）是流得很慢的重液体，rb 同样是湿度：
+//
+// 向下流动，比水重所以会沉到水底；黏度为 3，每 4 次更新才向斜下方流动一次。
+// 湿度慢慢蒸发，降到 150 以下时变回泥土。刚画出来的泥浆 rb 是 0，先给它一个 220 左右的初始湿度。
+pub fn update_mud(cell: Cell, mut api: SandApi) {
+    let mut rb = if cell.rb == 0 {
+        210 + api.rand_int(20) as u8
+    } else {
+        cell.rb
+    };
+    if api.once_in(10) {
+        rb = rb.saturating_sub(1);
+    }
+    let (nx, ny) = api.rand_vec_8();
+    let nbr = api.get(nx, ny).species;
+    if nbr == Species::Fire || nbr == Species::Lava {
+        rb = rb.saturating_sub(30);
+    }
+
+    if rb < 150 {
+        api.set(
+            0,
+            0,
+            Cell {
+                species: Species::Dirt,
+                ra: cell.ra,
+                rb,
+                clock: 0,
+            },
+        );
+        return;
+    }
+
+    let cell = Cell { rb, ..cell };
+    let dx = api.rand_dir_2();
+    let below = api.get(0, 1);
+    if below.species == Species::Empty {
+        api.set(0, 0, EMPTY_CELL);
+        api.set(0, 1, cell);
+    } else if below.species == Species::Water || below.species == Species::Oil {
+        api.set(0, 0, below);
+        api.set(0, 1, cell);
+    } else if sideways_reach(Species::Mud, &mut api) > 0 && api.get(dx, 1).species == Species::Empty
+    {
+        api.set(0, 0, EMPTY_CELL);
+        api.set(dx, 1, cell);
+    } else {
+        api.set(0, 0, cell);
+    }
+}
+
+// 盐（Salt）是像沙子一样下落的粉末：
+//
+// 碰到水时溶解，把那格水变成盐水，盐本身消失；碰到冰时把冰融化成水（见 reactions.rs）。
+pub fn update_salt(cell: Cell, mut api: SandApi) {
+    let dx = api.rand_dir_2();
+    let below = api.get(0, 1);
+    if below.species == Species::Empty {
+        api.set(0, 0, EMPTY_CELL);
+        api.set(0, 1, cell);
+    } else if api.get(dx, 1).species == Species::Empty {
+        api.set(0, 0, EMPTY_CELL);
+        api.set(dx, 1, cell);
+    } else if below.species == Species::Saltwater
+        || below.species == Species::Gas
+        || below.species == Species::Oil
+        || below.species == Species::Acid
+    {
+        api.set(0, 0, below);
+        api.set(0, 1, cell);
+    } else {
+        api.set(0, 0, cell);
+    }
+}
+
+// 盐水（Saltwater）是比水重的液体：
+//
+// 下方是水时和水交换位置，所以会沉到水底。
+// 不会被冰冻住（反应表只让冰冻结淡水），碰到冰时会让冰融化；
+// 碰到火焰或岩浆时水分蒸发，只留下盐的结晶（见 reactions.rs）。
+pub fn update_saltwater(cell: Cell, mut api: SandApi) {
+    if spurt(cell, &mut api) {
+        return;
+    }
+    let dx = api.rand_dir_2();
+    let reach = sideways_reach(Species::Saltwater, &mut api);
+    let below = api.get(0, 1);
+    let dx1 = api.get(dx, 1);
+    if below.species == Species::Empty
+        || below.species == Species::Oil
+        || below.species == Species::Water
+    {
+        api.set(0, 0, below);
+        api.set(0, 1, cell);
+    } else if dx1.species == Species::Empty || dx1.species == Species::Water {
+        api.set(0, 0, dx1);
+        api.set(dx, 1, cell);
+    } else if flow_sideways(cell, dx, reach, &mut api) {
+        // 横着流开，和水一样每次最多两格
+    } else if !flow_sideways(cell, -dx, reach, &mut api) {
+        api.set(0, 0, cell);
+    }
+}
+
+// 蜂蜜（Honey）是黏度很高的液体：
+//
+// 下方是空的或者是更轻的液体时直接往下落，但横向和斜向的流动要先通过黏度检查，所以会慢慢地摊开。
+pub fn update_honey(cell: Cell, mut api: SandApi) {
+    let dx = api.rand_dir_2();
+    let below = api.get(0, 1);
+    if below.species == Species::Empty {
+        api.set(0, 0, EMPTY_CELL);
+        api.set(0, 1, cell);
+    } else if below.species == Species::Water
+        || below.species == Species::Oil
+        || below.species == Species::Gas
+    {
+        api.set(0, 0, below);
+        api.set(0, 1, cell);
+    } else {
+        let reach = sideways_reach(Species::Honey, &mut api);
+        if reach > 0 && api.get(dx, 1).species == Species::Empty {
+            api.set(0, 0, EMPTY_CELL);
+            api.set(dx, 1, cell);
+        } else if !flow_sideways(cell, dx, reach, &mut api) {
+            api.set(0, 0, cell);
+        }
+    }
+}
+
+// 碱（Base，苛性碱）是像沙子一样下落的粉末，会沉到水和酸的下面。
+// 和酸相遇时发生中和反应，生成盐和水并放出气体（见 reactions.rs），可以用来控制泄漏的酸。
+pub fn update_base(cell: Cell, mut api: SandApi) {
+    let dx = api.rand_dir_2();
+    let below = api.get(0, 1);
+    if below.species == Species::Empty {
+        api.set(0, 0, EMPTY_CELL);
+        api.set(0, 1, cell);
+    } else if api.get(dx, 1).species == Species::Empty {
+        api.set(0, 0, EMPTY_CELL);
+        api.set(dx, 1, cell);
+    } else if below.species == Species::Water
+        || below.species == Species::Saltwater
+        || below.species == Species::Gas
+        || below.species == Species::Oil
+    {
+        api.set(0, 0, below);
+        api.set(0, 1, cell);
+    } else {
+        api.set(0, 0, cell);
+    }
+}
+
+// 黏土能粘住的固体
+fn clay_grips(species: Species) -> bool {
+    matches!(
+        species,
+        Species::Brick
+            | Species::Wall
+            | Species::Stone
+            | Species::Wood
+            | Species::Metal
+            | Species::Glass
+            | Species::Concrete
+    )
+}
+
+// 黏土（Clay）是有黏性的粉末：
+//
+// 不会像沙子那样往斜下方流；只要左右或上方粘着砖或其他固体，或者旁边是底下垫着东西的黏土，就保持原来的形状，即使泡在水里也不会散开。
+// 只有四周都没有可以粘住的东西时才会直接往下掉（可以沉到水里），悬在半空的一团黏土会整个掉下来。
+// 持续被火焰或岩浆加热时 rb 累积，超过 150 后烧成砖（Brick）；离开热源后慢慢冷却。
+pub fn update_clay(cell: Cell, mut api: SandApi) {
+    let (hx, hy) = api.rand_vec_8();
+    let heat_src = api.get(hx, hy).species;
+    let rb = if heat_src == Species::Fire || heat_src == Species::Lava {
+        cell.rb.saturating_add(2)
+    } else if api.once_in(4) {
+        cell.rb.saturating_sub(1)
+    } else {
+        cell.rb
+    };
+
+    if rb > 150 {
+        api.set(
+            0,
+            0,
+            Cell {
+                species: Species::Brick,
+                ra: cell.ra,
+                rb: 0,
+                clock: 0,
+            },
+        );
+        return;
+    }
+    let cell = Cell { rb, ..cell };
+
+    let mut stuck = false;
+    for &(sx, sy) in [(-1, 0), (1, 0), (0, -1)].iter() {
+        let s = api.get(sx, sy).species;
+        if clay_grips(s) {
+            stuck = true;
+        }
+        // 旁边的黏土只有自己底下垫着东西时才能把这一格粘住，光是黏土挨着黏土不算
+        if s == Species::Clay && sy == 0 {
+            let under = api.get(sx, 1).species;
+            if under != Species::Empty && !under.is_liquid() {
+                stuck = true;
+            }
+        }
+    }
+
+    let below = api.get(0, 1);
+    if !stuck && below.species == Species::Empty {
+        api.set(0, 0, EMPTY_CELL);
+        api.set(0, 1, cell);
+    } else if !stuck
+        && (below.species == Species::Water
+            || below.species == Species::Saltwater
+            || below.species == Species::Oil)
+    {
+        api.set(0, 0, below);
+        api.set(0, 1, cell);
+    } else {
+        api.set(0, 0, cell);
+    }
+}
+
+pub fn update_mite(cell: Cell, mut api: SandApi) {
+    // 0：踩到蜗牛的黏液时走不快
+    if slimed(&mut api) {
+        return;
+    }
+    // 1：初始设置：
+    // 生成一个随机整数，dx 和 dy 代表螨虫的移动方向。
+    // 根据 cell.ra 和 cell.rb 的值来调整 dx 和 dy，这决定了螨虫的移动方向。
+    let mut i = api.rand_int(100);
+    let mut dx = 0;
+
+    //2 方向控制：
+    //
+    // 如果 cell.ra < 20，螨虫向左移动（dx = (cell.ra as i32) - 1）。
+    // 如果 cell.rb > 10，螨虫向上移动（dy = -1），否则向下移动（dy = 1）。
+    if cell.ra < 20 {
+        dx = (cell.ra as i32) - 1;
+    }
+    let mut dy = 1;
+    let mut mite = cell.clone();
+
+
+    if cell.rb > 10 {
+        // /
+        mite.rb = mite.rb.saturating_sub(1);
+        dy = -1;
+    } else if cell.rb > 1 {
+        // \
+        mite.rb = mite.rb.saturating_sub(1);
+    } else {
+        // |
+        dx = 0;
+    }
+    //3 与邻居的互动：
+    //
+    // nbr = api.get(dx, dy)：获取螨虫将要移动到的目标位置的单元格。
+    // 随机决定 sx 和 sy，来采样周围的单元格。
+    let nbr = api.get(dx, dy);
+
+    let sx = (i % 3) - 1;
+    i = api.rand_int(1000);
+    let sy = (i % 3) - 1;
+    let sample = api.get(sx, sy).species;
+
+    // 4与特定物质的互动：
+    //
+    // 如果采样的物质是火（Fire）、岩浆（Lava）、水（Water）或油（Oil），螨虫会消失（设置为空单元格 EMPTY_CELL）。
+
+    if sample == Species::Fire
+        || sample == Species::Lava
+        || sample == Species::Water
+        || sample == Species::Saltwater
+        || sample == Species::Oil
+    {
+        api.set(0, 0, EMPTY_CELL);
+        return;
+    }
+    // // 如果采样的是植物类物质（Plant、Wood、Seed、Grass），并且随机值 i > 800，螨虫会移动到该位置。
+    if (sample == Species::Plant
+        || sample == Species::Wood
+        || sample == Species::Seed
+        || sample == Species::Grass)
+        && i > 800
+    {
+        api.set(0, 0, EMPTY_CELL);
+        api.set(sx, sy, cell);
+
+        return;
+    }
+
+    // 5处理尘土：
+    //
+    // 如果采样的物质是尘土（Dust），螨虫会根据随机概率决定是否消失或者停留
+    if sample == Species::Dust {
+        api.set(sx, sy, if i > 800 { cell } else { EMPTY_CELL });
+    }
+    // 6 螨虫的移动：
+    //
+    // 如果目标位置是空的（Species::Empty），螨虫会移动到那里。
+    if nbr.species == Species::Empty {
+        api.set(0, 0, EMPTY_CELL);
+        api.set(dx, dy, mite);
+    } else if dy == 1 && i > 800 {
+        // 如果周围被其它螨虫阻塞，螨虫可能会卡住或者改变方向。
+
+        i = api.rand_int(100);
+        let mut ndx = (i % 3) - 1;
+        if i < 6 {
+            //switch direction
+            ndx = dx;
+        }
+
+        mite.ra = (1 + ndx) as u8;
+        mite.rb = 10 + (i % 10) as u8; //hop height
+
+        api.set(0, 0, mite);
+    } else {
+        // 如果周围是冰块，螨虫会尝试在冰面上移动或者爬升。
+        if api.get(-1, 0).species == Species::Mite
+            && api.get(1, 0).species == Species::Mite
+            && api.get(0, -1).species == Species::Mite
+        {
+            api.set(0, 0, EMPTY_CELL);
         } else {
             //7 最终状态更新：
             //
@@ -1682,3 +2559,819 @@ pub fn update_mite(cell: Cell, mut api: SandApi) {
         }
     }
 }
+
+// 蚯蚓能钻过的松软土壤
+fn is_soil(species: Species) -> bool {
+    matches!(
+        species,
+        Species::Sand | Species::Dirt | Species::Mud | Species::WetSand
+    )
+}
+
+// 蚯蚓的身体最多有几节（不算头）
+const WORM_LENGTH: u8 = 6;
+
+// 蚯蚓（Worm）是蚯蚓的头，rb 是 100 + 前进方向（和火箭一样用 join_dy_dx 编码），rb 小于 100 表示还没有初始化：
+//
+// 头在沙子、泥土和泥巴里钻来钻去，吃掉前面的土，在身后留下一节身体（WormBody）。
+// 在土里时不太愿意钻出地面；悬空又不在土里时会往下掉；碰到水会掉头逃开；碰到火、岩浆或酸就死掉。
+pub fn update_worm(cell: Cell, mut api: SandApi) {
+    if slimed(&mut api) {
+        return;
+    }
+    let (nx, ny) = api.rand_vec_8();
+    let nbr_species = api.get(nx, ny).species;
+    if nbr_species == Species::Fire || nbr_species == Species::Lava || nbr_species == Species::Acid
+    {
+        api.set(0, 0, EMPTY_CELL);
+        return;
+    }
+
+    let mut heading = if cell.rb >= 100 {
+        split_dy_dx(cell.rb - 100)
+    } else {
+        (api.rand_dir_2(), 0)
+    };
+    let in_soil = [(0, 1), (0, -1), (1, 0), (-1, 0)]
+        .iter()
+        .any(|&(dx, dy)| is_soil(api.get(dx, dy).species));
+    let fleeing = nbr_species == Species::Water || nbr_species == Species::Saltwater;
+    let falling = !in_soil && api.get(0, 1).species == Species::Empty;
+
+    if falling {
+        heading = (0, 1);
+    } else if fleeing {
+        heading = (-nx, -ny);
+    } else if api.once_in(4) {
+        heading = if api.once_in(2) {
+            adjacency_left(heading)
+        } else {
+            adjacency_right(heading)
+        };
+    }
+
+    let (dx, dy) = heading;
+    let target = api.get(dx, dy).species;
+    // 在土里的时候，只有很小的机会会钻出地面
+    let surfacing = target == Species::Empty && in_soil && !fleeing && !api.once_in(10);
+    let can_move = is_soil(target) || (target == Species::Empty && !surfacing);
+
+    if can_move && (falling || fleeing || api.once_in(3)) {
+        let dir = 100 + join_dy_dx(dx, dy);
+        api.set(dx, dy, Cell { rb: dir, ..cell });
+        api.set(
+            0,
+            0,
+            Cell {
+                species: Species::WormBody,
+                ra: 1,
+                rb: dir,
+                clock: 0,
+            },
+        );
+    } else {
+        // 被挡住时转向，下次换个方向钻
+        if !can_move {
+            heading = if api.once_in(2) {
+                adjacency_left(adjacency_left(heading))
+            } else {
+                adjacency_right(adjacency_right(heading))
+            };
+        }
+        let (hx, hy) = heading;
+        api.set(
+            0,
+            0,
+            Cell {
+                rb: 100 + join_dy_dx(hx, hy),
+                ..cell
+            },
+        );
+    }
+}
+
+// 蚯蚓的身体（WormBody）不会自己动，头每走一步就在原来的位置留下一节新的身体：
+//
+// rb 是 100 + 指向前一节（更靠近头的那一节）的方向，ra 是这一节离头有几节，
+// 每次更新时从前一节的 ra 加一得到，所以头往前走一步，后面每一节的序号都会跟着变大。
+// 序号超过 WORM_LENGTH 的尾巴会消失，在土里的话留下一格松土（Dirt）；
+// 前一节不见了（蚯蚓断了）或者碰到火、岩浆、酸的时候，这一节也会死掉，后面的身体跟着一节一节地消失。
+pub fn update_worm_body(cell: Cell, mut api: SandApi) {
+    let (nx, ny) = api.rand_vec_8();
+    let nbr_species = api.get(nx, ny).species;
+    let hurt = nbr_species == Species::Fire
+        || nbr_species == Species::Lava
+        || nbr_species == Species::Acid;
+
+    let index = if cell.rb < 100 || hurt {
+        u8::MAX
+    } else {
+        let (fx, fy) = split_dy_dx(cell.rb - 100);
+        let front = api.get(fx, fy);
+        match front.species {
+            Species::Worm => 1,
+            Species::WormBody => front.ra.saturating_add(1),
+            _ => u8::MAX,
+        }
+    };
+
+    if index <= WORM_LENGTH {
+        api.set(0, 0, Cell { ra: index, ..cell });
+        return;
+    }
+    let loosened = [(0, 1), (0, -1), (1, 0), (-1, 0)]
+        .iter()
+        .any(|&(dx, dy)| is_soil(api.get(dx, dy).species));
+    if loosened && !hurt {
+        let ra = 100 + api.rand_int(30) as u8;
+        api.set(
+            0,
+            0,
+            Cell {
+                species: Species::Dirt,
+                ra,
+                rb: 0,
+                clock: 0,
+            },
+        );
+    } else {
+        api.set(0, 0, EMPTY_CELL);
+    }
+}
+
+// 蚂蚁脚下有东西可以抓住：下方或者左右两边不是空的
+fn ant_can_stand(dx: i32, dy: i32, api: &mut SandApi) -> bool {
+    api.get(dx, dy + 1).species != Species::Empty
+        || api.get(dx - 1, dy).species != Species::Empty
+        || api.get(dx + 1, dy).species != Species::Empty
+}
+
+// 蚂蚁（Ant）的 rb 是 100 + 前进方向（空手）或者 150 + 前进方向（叼着食物），rb 小于 100 表示还没有初始化：
+//
+// 空手的蚂蚁四处闲逛，碰到种子或植物就叼起一块，然后掉头往回走。
+// 叼着食物的蚂蚁每走一步都在脚下留下信息素，碰到蚁丘（Anthill）就把食物放进去，再掉头出去找吃的。
+// 信息素每帧都在消散，所以离食物越近的地方气味越淡；空手的蚂蚁闻到气味时会往气味更淡的方向走，一路找回食物。
+// 悬空时会掉下去，碰到水、火、岩浆或酸会死掉。
+pub fn update_ant(cell: Cell, mut api: SandApi) {
+    if slimed(&mut api) {
+        return;
+    }
+    let (nx, ny) = api.rand_vec_8();
+    let nbr = api.get(nx, ny);
+    if nbr.species == Species::Water
+        || nbr.species == Species::Saltwater
+        || nbr.species == Species::Fire
+        || nbr.species == Species::Lava
+        || nbr.species == Species::Acid
+    {
+        api.set(0, 0, EMPTY_CELL);
+        return;
+    }
+    if !ant_can_stand(0, 0, &mut api) {
+        api.set(0, 0, EMPTY_CELL);
+        api.set(0, 1, cell);
+        return;
+    }
+
+    let carrying = cell.rb >= 150;
+    let (mut hx, mut hy) = if cell.rb >= 150 {
+        split_dy_dx(cell.rb - 150)
+    } else if cell.rb >= 100 {
+        split_dy_dx(cell.rb - 100)
+    } else {
+        (api.rand_dir_2(), 0)
+    };
+
+    if !carrying && (nbr.species == Species::Seed || nbr.species == Species::Plant) {
+        api.set(nx, ny, EMPTY_CELL);
+        api.set(
+            0,
+            0,
+            Cell {
+                rb: 150 + join_dy_dx(-hx, -hy),
+                ..cell
+            },
+        );
+        return;
+    }
+    if carrying && nbr.species == Species::Anthill {
+        api.set(
+            nx,
+            ny,
+            Cell {
+                rb: nbr.rb.saturating_add(1),
+                ..nbr
+            },
+        );
+        api.set(
+            0,
+            0,
+            Cell {
+                rb: 100 + join_dy_dx(-hx, -hy),
+                ..cell
+            },
+        );
+        return;
+    }
+
+    // 空手的蚂蚁顺着信息素往气味最淡（最旧）的地方走，也就是食物那一头
+    let mut trail = None;
+    if !carrying && api.get_scent(0, 0) > 0 && !api.once_in(4) {
+        let mut faintest = u8::MAX;
+        for dx in -1..=1 {
+            for dy in -1..=1 {
+                let scent = api.get_scent(dx, dy);
+                if scent > 0
+                    && scent < faintest
+                    && api.get(dx, dy).species == Species::Empty
+                    && ant_can_stand(dx, dy, &mut api)
+                {
+                    faintest = scent;
+                    trail = Some((dx, dy));
+                }
+            }
+        }
+    }
+    if let Some((dx, dy)) = trail {
+        hx = dx;
+        hy = dy;
+    } else if api.once_in(if carrying { 8 } else { 4 }) {
+        let (tx, ty) = if api.once_in(2) {
+            adjacency_left((hx, hy))
+        } else {
+            adjacency_right((hx, hy))
+        };
+        hx = tx;
+        hy = ty;
+    }
+
+    let base = if carrying { 150 } else { 100 };
+    if api.once_in(2) {
+        api.set(
+            0,
+            0,
+            Cell {
+                rb: base + join_dy_dx(hx, hy),
+                ..cell
+            },
+        );
+        return;
+    }
+    // 沿着前进方向走，被挡住时试着往上爬或往下走
+    let step = [(hx, hy), (hx, -1), (hx, 1)]
+        .iter()
+        .cloned()
+        .find(|&(dx, dy)| {
+            (dx, dy) != (0, 0)
+                && api.get(dx, dy).species == Species::Empty
+                && ant_can_stand(dx, dy, &mut api)
+        });
+    match step {
+        Some((dx, dy)) => {
+            if carrying {
+                api.set_scent(255);
+            }
+            api.set(0, 0, EMPTY_CELL);
+            api.set(
+                dx,
+                dy,
+                Cell {
+                    rb: base + join_dy_dx(dx, dy),
+                    ..cell
+                },
+            );
+        }
+        None => {
+            api.set(
+                0,
+                0,
+                Cell {
+                    rb: base + join_dy_dx(-hx, -hy),
+                    ..cell
+                },
+            );
+        }
+    }
+}
+
+// 蚁丘（Anthill）像沙子一样堆着，rb 是蚂蚁搬进来的食物数量。
+// 攒够 4 份食物就在旁边的空地上孵出一只新蚂蚁。
+pub fn update_anthill(cell: Cell, mut api: SandApi) {
+    if api.get(0, 1).species == Species::Empty {
+        api.set(0, 0, EMPTY_CELL);
+        api.set(0, 1, cell);
+        return;
+    }
+    let (dx, dy) = api.rand_vec_8();
+    if cell.rb >= 4 && api.get(dx, dy).species == Species::Empty {
+        api.set(
+            dx,
+            dy,
+            Cell {
+                species: Species::Ant,
+                ra: cell.ra,
+                rb: 0,
+                clock: 0,
+            },
+        );
+        api.set(
+            0,
+            0,
+            Cell {
+                rb: cell.rb - 4,
+                ..cell
+            },
+        );
+    }
+}
+
+// 黏液会拖慢小动物：脚下或者身边有黏液的时候，三次更新里有两次动不了
+fn slimed(api: &mut SandApi) -> bool {
+    let touching = [(0, 1), (0, -1), (1, 0), (-1, 0)]
+        .iter()
+        .any(|&(dx, dy)| api.get(dx, dy).species == Species::Slime);
+    touching && !api.once_in(3)
+}
+
+// 蜗牛能吸附的表面：不是空气、液体、黏液或者别的蜗牛
+fn snail_grips(species: Species) -> bool {
+    species != Species::Empty
+        && species != Species::Slime
+        && species != Species::Snail
+        && !species.is_liquid()
+}
+
+// (dx, dy) 这个位置上下左右至少有一面可以吸附
+fn snail_can_cling(dx: i32, dy: i32, api: &mut SandApi) -> bool {
+    [(0, 1), (0, -1), (1, 0), (-1, 0)]
+        .iter()
+        .any(|&(sx, sy)| snail_grips(api.get(dx + sx, dy + sy).species))
+}
+
+// 蜗牛（Snail）的 rb 是 100 + 爬行方向，rb 小于 100 表示还没有初始化：
+//
+// 蜗牛爬得很慢，能吸附在任何固体表面上，包括墙壁和天花板；离开表面就会掉下去。
+// 每次优先沿着原来的方向爬，走不通就一点点转向，所以会沿着表面绕着走。
+// 爬过的地方会留下一格黏液（Slime），顺路吃掉旁边的植物、真菌和马勃。碰到火、岩浆、酸或盐就会死掉。
+pub fn update_snail(cell: Cell, mut api: SandApi) {
+    let (nx, ny) = api.rand_vec_8();
+    let nbr_species = api.get(nx, ny).species;
+    if nbr_species == Species::Fire
+        || nbr_species == Species::Lava
+        || nbr_species == Species::Acid
+        || nbr_species == Species::Salt
+    {
+        api.set(0, 0, EMPTY_CELL);
+        return;
+    }
+    if (nbr_species == Species::Plant
+        || nbr_species == Species::Fungus
+        || nbr_species == Species::PuffCap)
+        && api.once_in(6)
+    {
+        api.set(nx, ny, EMPTY_CELL);
+    }
+
+    if !snail_can_cling(0, 0, &mut api) {
+        if api.get(0, 1).species == Species::Empty {
+            api.set(0, 0, EMPTY_CELL);
+            api.set(0, 1, cell);
+        }
+        return;
+    }
+    if !api.once_in(8) {
+        return;
+    }
+
+    let mut heading = if cell.rb >= 100 {
+        split_dy_dx(cell.rb - 100)
+    } else {
+        (api.rand_dir_2(), 0)
+    };
+    if api.once_in(50) {
+        heading = (-heading.0, -heading.1);
+    }
+    // 依次尝试原方向、左右各转 45 度、90 度、135 度，最后掉头
+    let mut candidates = [heading; 8];
+    let (mut left, mut right) = (heading, heading);
+    for i in 0..3 {
+        left = adjacency_left(left);
+        right = adjacency_right(right);
+        candidates[1 + i * 2] = left;
+        candidates[2 + i * 2] = right;
+    }
+    candidates[7] = (-heading.0, -heading.1);
+
+    let step = candidates.iter().cloned().find(|&(dx, dy)| {
+        (dx, dy) != (0, 0)
+            && api.get(dx, dy).species == Species::Empty
+            && snail_can_cling(dx, dy, &mut api)
+    });
+    if let Some((dx, dy)) = step {
+        api.set(
+            0,
+            0,
+            Cell {
+                species: Species::Slime,
+                ra: cell.ra,
+                rb: 120,
+                clock: 0,
+            },
+        );
+        api.set(
+            dx,
+            dy,
+            Cell {
+                rb: 100 + join_dy_dx(dx, dy),
+                ..cell
+            },
+        );
+    }
+}
+
+// 黏液（Slime）不会动，rb 是它还能留多久，数到 1 就干掉消失。刚画出来的黏液 rb 是 0，和蜗牛留下的一样从 120 开始数。
+pub fn update_slime(cell: Cell, mut api: SandApi) {
+    let rb = if cell.rb == 0 { 120 } else { cell.rb };
+    if rb == 1 {
+        api.set(0, 0, EMPTY_CELL);
+        return;
+    }
+    if api.once_in(2) {
+        api.set(0, 0, Cell { rb: rb - 1, ..cell });
+    } else if rb != cell.rb {
+        api.set(0, 0, Cell { rb, ..cell });
+    }
+}
+
+// 鱼（Fish）只能活在水或盐水里，rb 记录它的状态：
+//
+// 100 + 游动方向表示在水里游；200 以上表示被困在空气里，每次更新加一，到 255 就死掉；小于 100 表示还没有初始化。
+// 在水里时和水交换位置往前游，会模仿附近同伴的方向，聚成一群一起游；偶尔啃掉旁边的植物。
+// 离开水以后会往下掉、在地上乱蹦，过一会儿就死了。碰到火、岩浆或酸会直接死掉。
+pub fn update_fish(cell: Cell, mut api: SandApi) {
+    let (nx, ny) = api.rand_vec_8();
+    let nbr = api.get(nx, ny);
+    if nbr.species == Species::Fire || nbr.species == Species::Lava || nbr.species == Species::Acid
+    {
+        api.set(0, 0, EMPTY_CELL);
+        return;
+    }
+
+    let in_water = [(0, 1), (0, -1), (1, 0), (-1, 0)].iter().any(|&(dx, dy)| {
+        let species = api.get(dx, dy).species;
+        species == Species::Water || species == Species::Saltwater
+    });
+    if !in_water {
+        // 搁浅：往下掉或者在原地乱蹦，rb 从 200 开始数到 255
+        let rb = if cell.rb >= 200 { cell.rb } else { 200 };
+        if rb == 255 {
+            api.set(0, 0, EMPTY_CELL);
+            return;
+        }
+        let fish = Cell { rb: rb + 1, ..cell };
+        let hop = api.rand_dir();
+        if api.get(0, 1).species == Species::Empty {
+            api.set(0, 0, EMPTY_CELL);
+            api.set(0, 1, fish);
+        } else if api.once_in(5) && api.get(hop, -1).species == Species::Empty {
+            api.set(0, 0, EMPTY_CELL);
+            api.set(hop, -1, fish);
+        } else {
+            api.set(0, 0, fish);
+        }
+        return;
+    }
+
+    let mut heading = if cell.rb >= 100 && cell.rb < 200 {
+        split_dy_dx(cell.rb - 100)
+    } else {
+        (api.rand_dir_2(), 0)
+    };
+
+    // 啃食水草
+    if (nbr.species == Species::Plant || nbr.species == Species::Kelp) && api.once_in(10) {
+        let water = api.get(-nx, -ny);
+        if water.species == Species::Water || water.species == Species::Saltwater {
+            api.set(nx, ny, water);
+        }
+    }
+
+    // 和附近的同伴对齐方向，形成鱼群
+    let (sx, sy) = (api.rand_int(5) - 2, api.rand_int(5) - 2);
+    let mate = api.get(sx, sy);
+    if mate.species == Species::Fish && mate.rb >= 100 && mate.rb < 200 && !api.once_in(3) {
+        heading = split_dy_dx(mate.rb - 100);
+    } else if api.once_in(12) {
+        heading = if api.once_in(2) {
+            adjacency_left(heading)
+        } else {
+            adjacency_right(heading)
+        };
+    }
+
+    let (dx, dy) = heading;
+    let target = api.get(dx, dy);
+    let swims = target.species == Species::Water || target.species == Species::Saltwater;
+    if swims && api.once_in(2) {
+        api.set(0, 0, target);
+        api.set(
+            dx,
+            dy,
+            Cell {
+                rb: 100 + join_dy_dx(dx, dy),
+                ..cell
+            },
+        );
+        return;
+    }
+    if !swims {
+        // 游不过去就转个大弯
+        heading = if api.once_in(2) {
+            adjacency_left(adjacency_left(heading))
+        } else {
+            adjacency_right(adjacency_right(heading))
+        };
+    }
+    let (hx, hy) = heading;
+    api.set(
+        0,
+        0,
+        Cell {
+            rb: 100 + join_dy_dx(hx, hy),
+            ..cell
+        },
+    );
+}
+
+// 海带能扎根的东西：沙子（包括泡湿的沙子）、石头，以及下面的海带
+fn kelp_holds(species: Species) -> bool {
+    matches!(
+        species,
+        Species::Kelp | Species::Sand | Species::WetSand | Species::Stone
+    )
+}
+
+// (dx, dy) 这个位置的正下方或斜下方有没有能扎根的东西
+fn kelp_anchored(dx: i32, dy: i32, api: &mut SandApi) -> bool {
+    (-1..=1).any(|sx| kelp_holds(api.get(dx + sx, dy + 1).species))
+}
+
+// 海带（Kelp）长在水底的沙子或石头上，rb 是 100 + 还能往上长几格，rb 小于 100 表示还没有初始化：
+//
+// 顶端的海带上方是水时会慢慢往上长，每长一格高度减一，所以一株海带长到一定高度就停了。
+// 每一格都可以左右摆动一格，只要自己和上面那一格还连着就行；水流（所在位置的风）会把它往一边推。
+// 没有扎根的海带会慢慢沉下去，水干了的话会枯死。
+pub fn update_kelp(cell: Cell, mut api: SandApi) {
+    let wet = [(0, 1), (0, -1), (1, 0), (-1, 0)].iter().any(|&(dx, dy)| {
+        let species = api.get(dx, dy).species;
+        species == Species::Water || species == Species::Saltwater
+    });
+    if !wet {
+        if api.once_in(30) {
+            api.set(0, 0, EMPTY_CELL);
+        }
+        return;
+    }
+
+    if !kelp_anchored(0, 0, &mut api) {
+        let below = api.get(0, 1);
+        if below.species == Species::Empty
+            || ((below.species == Species::Water || below.species == Species::Saltwater)
+                && api.once_in(3))
+        {
+            api.set(0, 0, below);
+            api.set(0, 1, cell);
+        }
+        return;
+    }
+
+    let cell = if cell.rb < 100 {
+        let rb = 110 + api.rand_int(20) as u8;
+        Cell { rb, ..cell }
+    } else {
+        cell
+    };
+
+    // 往上长
+    let tip = (-1..=1).all(|sx| api.get(sx, -1).species != Species::Kelp);
+    let gx = api.rand_dir();
+    let above = api.get(gx, -1);
+    if tip
+        && cell.rb > 100
+        && (above.species == Species::Water || above.species == Species::Saltwater)
+        && api.once_in(20)
+    {
+        api.set(
+            gx,
+            -1,
+            Cell {
+                rb: cell.rb - 1,
+                ..cell
+            },
+        );
+    }
+
+    // 随水流摆动
+    let wx = (api.get_fluid().dy as i32) - 126;
+    let dx = if wx > 10 {
+        1
+    } else if wx < -10 {
+        -1
+    } else {
+        api.rand_dir_2()
+    };
+    let side = api.get(dx, 0);
+    let attached =
+        (-1..=1).all(|sx| api.get(sx, -1).species != Species::Kelp || (sx - dx).abs() <= 1);
+    if (side.species == Species::Water || side.species == Species::Saltwater)
+        && (wx.abs() > 10 || api.once_in(40))
+        && attached
+        && kelp_anchored(dx, 0, &mut api)
+    {
+        api.set(0, 0, side);
+        api.set(dx, 0, cell);
+        return;
+    }
+    api.set(0, 0, cell);
+}
+
+// 草能长在上面的土：泥土和沙子
+fn grass_soil(species: Species) -> bool {
+    matches!(species, Species::Dirt | Species::Sand | Species::WetSand)
+}
+
+// 草（Grass）只长在泥土或沙子露天的表面上：
+//
+// 下面是土、上面是空的时候，会慢慢往左右两边（包括高一格、低一格的地面）蔓延，在泥土上比在沙子上长得快。
+// 被别的东西埋住以后会烂成泥土；下面悬空就往下掉，下面不是土就慢慢枯死。碰到火或岩浆一下子就烧起来（见 reactions.rs）。
+pub fn update_grass(cell: Cell, mut api: SandApi) {
+    let below = api.get(0, 1).species;
+    if below == Species::Empty {
+        api.set(0, 0, EMPTY_CELL);
+        api.set(0, 1, cell);
+        return;
+    }
+    if api.get(0, -1).species != Species::Empty {
+        if api.once_in(20) {
+            let ra = 100 + api.rand_int(30) as u8;
+            api.set(
+                0,
+                0,
+                Cell {
+                    species: Species::Dirt,
+                    ra,
+                    rb: 0,
+                    clock: 0,
+                },
+            );
+        }
+        return;
+    }
+    if !grass_soil(below) {
+        if api.once_in(50) {
+            api.set(0, 0, EMPTY_CELL);
+        }
+        return;
+    }
+
+    let rate = if below == Species::Dirt { 60 } else { 200 };
+    let dx = api.rand_dir_2();
+    let dy = api.rand_dir();
+    if api.once_in(rate)
+        && api.get(dx, dy).species == Species::Empty
+        && api.get(dx, dy - 1).species == Species::Empty
+        && grass_soil(api.get(dx, dy + 1).species)
+    {
+        let ra = (cell.ra as i32 + api.rand_dir() * 5) as u8;
+        api.set(
+            dx,
+            dy,
+            Cell {
+                species: Species::Grass,
+                ra,
+                rb: 0,
+                clock: 0,
+            },
+        );
+    }
+}
+
+// 花（Flower）开在茎的顶上，ra 是花色，rb 是花的年龄：
+//
+// 花慢慢变老，老到 200 以后开始凋谢，往旁边的空地上掉种子，每掉一颗年龄加 20，到 250 就彻底谢掉。
+// 掉下来的种子的 ra 是花色加上一点随机的偏移，所以下一代的花色和高度都和这一朵差不多。
+// 下面悬空会往下掉，碰到火或岩浆会烧起来（见 reactions.rs）。
+pub fn update_flower(cell: Cell, mut api: SandApi) {
+    let (dx, dy) = api.rand_vec_8();
+    let nbr_species = api.get(dx, dy).species;
+    if api.get(0, 1).species == Species::Empty {
+        api.set(0, 0, EMPTY_CELL);
+        api.set(0, 1, cell);
+        return;
+    }
+
+    let mut rb = cell.rb;
+    if rb < 200 {
+        if api.once_in(4) {
+            rb += 1;
+        }
+    } else if nbr_species == Species::Empty && api.once_in(3) {
+        let ra = (cell.ra as i32 + api.rand_int(21) - 10).clamp(70, 250) as u8;
+        api.set(
+            dx,
+            dy,
+            Cell {
+                species: Species::Seed,
+                ra,
+                rb: 0,
+                clock: 0,
+            },
+        );
+        rb = rb.saturating_add(20);
+    }
+
+    if rb >= 250 {
+        api.set(0, 0, EMPTY_CELL);
+        return;
+    }
+    api.set(0, 0, Cell { rb, ..cell });
+}
+
+// 藤蔓能攀附的表面：墙、木头和石头
+fn vine_support(species: Species) -> bool {
+    matches!(species, Species::Wall | Species::Wood | Species::Stone)
+}
+
+// (dx, dy) 这个位置上下左右有没有能攀附的表面
+fn vine_touches(dx: i32, dy: i32, api: &mut SandApi) -> bool {
+    [(0, 1), (0, -1), (1, 0), (-1, 0)]
+        .iter()
+        .any(|&(sx, sy)| vine_support(api.get(dx + sx, dy + sy).species))
+}
+
+// 藤蔓（Vine）贴着墙、木头和石头的表面往任何方向爬，包括往上爬和绕过悬空的边缘，rb 和植物一样是燃烧的倒计时：
+//
+// 只有贴着表面的藤蔓才会往外长，而且新长出来的一格也必须贴着表面；旁边已经有好几格藤蔓的地方不再长，所以藤蔓是一条条的。
+// 既没有贴着表面、周围也没有别的藤蔓时会掉下去。
+// 碰到火或岩浆会像植物一样烧起来（点燃写在 reactions.rs 的反应表里）：rb 从 20 开始倒数，烧的时候往旁边的空地上喷火，碰到水就熄灭，数到 1 就烧完了。
+pub fn update_vine(cell: Cell, mut api: SandApi) {
+    let rb = cell.rb;
+    let (dx, dy) = api.rand_vec_8();
+    let nbr_species = api.get(dx, dy).species;
+
+    if rb > 1 {
+        api.set(0, 0, Cell { rb: rb - 1, ..cell });
+        if nbr_species == Species::Empty {
+            let ra = 20 + api.rand_int(30) as u8;
+            api.set(
+                dx,
+                dy,
+                Cell {
+                    species: Species::Fire,
+                    ra,
+                    rb: 0,
+                    clock: 0,
+                },
+            );
+        }
+        if nbr_species == Species::Water {
+            api.set(0, 0, Cell { rb: 0, ..cell });
+        }
+        return;
+    } else if rb == 1 {
+        api.set(0, 0, EMPTY_CELL);
+        return;
+    }
+
+    let supported = vine_touches(0, 0, &mut api);
+    if !supported {
+        let attached = (-1..=1).any(|sx| {
+            (-1..=1).any(|sy| (sx, sy) != (0, 0) && api.get(sx, sy).species == Species::Vine)
+        });
+        if !attached && api.get(0, 1).species == Species::Empty {
+            api.set(0, 0, EMPTY_CELL);
+            api.set(0, 1, cell);
+        }
+        return;
+    }
+
+    if nbr_species == Species::Empty && api.once_in(30) && vine_touches(dx, dy, &mut api) {
+        let crowd = (-1..=1)
+            .flat_map(|sx| (-1..=1).map(move |sy| (sx, sy)))
+            .filter(|&(sx, sy)| api.get(dx + sx, dy + sy).species == Species::Vine)
+            .count();
+        if crowd <= 2 {
+            let ra = (cell.ra as i32 + api.rand_dir() * 4) as u8;
+            api.set(
+                dx,
+                dy,
+                Cell {
+                    species: Species::Vine,
+                    ra,
+                    rb: 0,
+                    clock: 0,
+                },
+            );
+        }
+    }
+}
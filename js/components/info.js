@@ -1,4 +1,10 @@
 import React from "react";
+import { Species, angle_of_repose } from "../../crate/pkg/sandtable";
+
+// 颗粒物的休止角，数值来自 species.rs 里的 Species::repose
+const Repose = ({ species }) => (
+  <i> Piles at {angle_of_repose(species)}°.</i>
+);
 
 const Info = () => {
   return (
@@ -47,11 +53,13 @@ const Info = () => {
       <h4>Wall </h4>
       Indestructible.
       <h4>Sand </h4>
-      Sinks in water.
+      Sinks in water. Melts into glass when heated.
+      <Repose species={Species.Sand} />
       <h4>Water </h4>
       Puts out fire.
       <h4>Stone </h4>
       Forms arches, turns into sand under pressure.
+      <Repose species={Species.Stone} />
       <h4>Ice </h4>
       Freezes Water, slippery!
       <h4>Gas </h4>
@@ -61,13 +69,14 @@ const Info = () => {
       <h4>Mite </h4>
       Eats wood and plant, but loves dust! Slides on ice..
       <h4>Wood </h4>
-      Sturdy, but biodegradable.
+      Sturdy, but biodegradable. Floats on water.
       <h4>Plant </h4>
       Thrives in wet enviroments.
       <h4>Fungus </h4>
-      Spreads over everything.
+      Spreads over everything. Sprouts puff-caps.
       <h4>Seed </h4>
       Grows on sand, plant, and fungus.
+      <Repose species={Species.Seed} />
       <h4>Fire </h4>
       Hot!
       <h4>Lava </h4>
@@ -76,10 +85,71 @@ const Info = () => {
       Corrodes other elements.
       <h4>Dust </h4>
       Pretty, but dangerously explosive.
+      <Repose species={Species.Dust} />
       <h4>Oil </h4>
       Produces smoke when set on fire.
       <h4>Rocket </h4>
       Explodes into copies of the first element it touches.
+      <h4>Glass </h4>
+      Melted sand. Resists acid and blocks the wind.
+      <h4>Metal </h4>
+      Conducts heat. Rusts in water, and slowly dissolves in acid.
+      <h4>Rust </h4>
+      Crumbly powder left behind by wet metal.
+      <h4>Cement </h4>
+      Mix with water to pour concrete.
+      <h4>Concrete </h4>
+      Binds sand and stone while wet, then hardens and holds its shape.
+      <h4>Dirt </h4>
+      Soaks up water. Seeds and plants grow best in it.
+      <h4>Mud </h4>
+      Saturated dirt. Oozes slowly and dries back out.
+      <h4>Wet Sand </h4>
+      Clumps into steep piles. Build a sandcastle!
+      <Repose species={Species.WetSand} />
+      <h4>Salt </h4>
+      Dissolves in water and melts ice.
+      <h4>Saltwater </h4>
+      Heavier than water and won't freeze. Boil it to get the salt back.
+      <h4>Base </h4>
+      Neutralizes acid into salt and water, fizzing off gas.
+      <h4>Clay </h4>
+      Sticky and easy to shape. Bake it with fire to make bricks.
+      <h4>Brick </h4>
+      Fireproof and tough against acid.
+      <h4>Honey </h4>
+      Thick and slow to spread.
+      <h4>Puff-cap </h4>
+      Grows out of fungus and puffs spores into the wind when ripe.
+      <h4>Spore </h4>
+      Drifts on the lightest breeze. Sprouts fungus on wood and plants.
+      <h4>Worm </h4>
+      Tunnels through sand, dirt and mud, leaving loosened soil behind. Hates
+      getting wet.
+      <h4>Ant </h4>
+      Carries seeds and plant scraps home, leaving a scent trail for the others
+      to follow.
+      <h4>Anthill </h4>
+      Ants store food here. Well fed hills hatch new ants.
+      <h4>Snail </h4>
+      Crawls slowly over any surface, even ceilings, munching plants and fungus.
+      Can't stand salt.
+      <h4>Slime </h4>
+      Left behind by snails. Slows down other critters, then dries up.
+      <h4>Fish </h4>
+      Swims in schools and nibbles water plants. Flops around and dies out of
+      water.
+      <h4>Kelp </h4>
+      Grows up from sand and stone under water, swaying with the current. Dries
+      out without water.
+      <h4>Grass </h4>
+      Carpets the top of dirt and sand. Mites love it and it burns in a flash.
+      <h4>Flower </h4>
+      Blooms on top of some seedlings. Wilts and scatters seeds that grow
+      flowers of a similar colour.
+      <h4>Vine </h4>
+      Climbs along walls, wood and stone, even across ceilings. Burns like
+      plants.
       <h4>Empty </h4>
       Erases.
       <hr />
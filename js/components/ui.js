@@ -67,6 +67,8 @@ class Index extends React.Component {
     this.state = {
       submissionMenuOpen: false,
       paused: false,
+      structural: false,
+      momentum: false,
       submitting: false,
       size: 2,
       dataURL: {},
@@ -111,6 +113,14 @@ class Index extends React.Component {
     window.paused = !this.state.paused;
     this.setState({ paused: !this.state.paused });
   }
+  toggleStructural() {
+    universe.set_structural(!this.state.structural);
+    this.setState({ structural: !this.state.structural });
+  }
+  toggleMomentum() {
+    universe.set_momentum(!this.state.momentum);
+    this.setState({ momentum: !this.state.momentum });
+  }
   play() {
     window.paused = false;
     this.setState({ paused: false });
@@ -378,7 +388,14 @@ class Index extends React.Component {
   }
 
   render() {
-    let { size, paused, selectedElement, currentSubmission } = this.state;
+    let {
+      size,
+      paused,
+      structural,
+      momentum,
+      selectedElement,
+      currentSubmission,
+    } = this.state;
     let hash =
       currentSubmission && currentSubmission.id
         ? `#${currentSubmission.id}`
@@ -417,6 +434,18 @@ class Index extends React.Component {
         )}
 
         <button onClick={() => this.reset()}>Reset</button>
+        <button
+          onClick={() => this.toggleStructural()}
+          className={structural ? "selected" : ""}
+        >
+          Collapse
+        </button>
+        <button
+          onClick={() => this.toggleMomentum()}
+          className={momentum ? "selected" : ""}
+        >
+          Momentum
+        </button>
         <Link
           to={{
             pathname: "/info/",
@@ -461,6 +490,8 @@ class Index extends React.Component {
         </button>
         {Object.keys(Species)
           .filter((x) => !Number.isInteger(Number.parseInt(x)))
+          // 蚯蚓的身体只能由蚯蚓自己长出来
+          .filter((x) => x !== "WormBody")
           .map((n) =>
             ElementButton(n, selectedElement, (id) =>
               this.setState({ selectedElement: id })
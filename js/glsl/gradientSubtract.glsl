@@ -28,7 +28,7 @@ void main() {
   int type = int((cell.r * 255.) + 0.1);
 
   // || type == 7 || type == 9
-  if (type == 1 || type == 5) {
+  if (type == 1 || type == 5 || type == 20 || type == 32) {
     velocity = vec2(0.);
   }
   if (type == 0 || type == 4 || type == 6) {
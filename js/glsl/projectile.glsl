@@ -0,0 +1,16 @@
+precision mediump float;
+varying float type;
+
+#pragma glslify: hsv2rgb = require('glsl-hsv2rgb')
+
+// 飞行粒子只区分火星、熔岩和其他碎片三种颜色
+void main() {
+  int t = int(type + 0.1);
+  vec3 color = hsv2rgb(vec3(0.1, 0.3, 0.7));
+  if (t == 6) { // fire
+    color = hsv2rgb(vec3(0.08, 0.7, 1.0));
+  } else if (t == 8) { // lava
+    color = hsv2rgb(vec3(0.03, 0.6, 0.95));
+  }
+  gl_FragColor = vec4(color, 1.0);
+}
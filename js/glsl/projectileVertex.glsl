@@ -0,0 +1,11 @@
+// 飞行粒子的顶点着色器：每个粒子画成一个方点，大小和一个格子一样
+precision mediump float;
+attribute vec2 position;
+attribute float species;
+uniform float size;
+varying float type;
+void main() {
+  type = species;
+  gl_PointSize = size;
+  gl_Position = vec4(position, 0, 1);
+}
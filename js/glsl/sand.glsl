@@ -79,6 +79,110 @@ void main() {
     hue = (data.g * 0.1);
     saturation = 0.3;
     lightness = 0.3 + data.g * 0.3;
+  } else if (type == 23) { // cement
+    hue = 0.1;
+    saturation = 0.05;
+    lightness = 0.7 + data.g * 0.2;
+  } else if (type == 24) { // concrete
+    hue = 0.1;
+    saturation = 0.05;
+    lightness = 0.5 + data.g * 0.2 - data.b * 0.3;
+  } else if (type == 25) { // dirt
+    hue = 0.07;
+    saturation = 0.5;
+    lightness = 0.45 + data.g * 0.2 - data.b * 0.4;
+  } else if (type == 26) { // mud
+    hue = 0.06;
+    saturation = 0.5;
+    lightness = 0.2 + data.g * 0.15 + noise * 0.03;
+  } else if (type == 27) { // wet sand
+    hue = 0.09;
+    saturation = 0.5 + data.b * 0.3;
+    lightness += 0.2 - data.b * 0.4;
+  } else if (type == 28) { // salt
+    hue = 0.0;
+    saturation = 0.05;
+    lightness = 0.9 + data.g * 0.1;
+  } else if (type == 29) { // saltwater
+    hue = 0.52;
+    lightness = 0.65 + data.g * 0.25 + noise * 0.1;
+  } else if (type == 30) { // base
+    hue = 0.75;
+    saturation = 0.25;
+    lightness = 0.8 + data.g * 0.1;
+  } else if (type == 31) { // clay
+    hue = 0.04 + data.b * 0.1;
+    saturation = 0.35;
+    lightness = 0.55 + data.g * 0.2;
+  } else if (type == 32) { // brick
+    hue = 0.01;
+    saturation = 0.6;
+    lightness = 0.35 + data.g * 0.3;
+  } else if (type == 33) { // honey
+    hue = 0.11;
+    saturation = 0.85;
+    lightness = 0.75 + data.g * 0.15 + noise * 0.03;
+  } else if (type == 34) { // puff-cap
+    hue = 0.08;
+    saturation = 0.3;
+    lightness = 0.8 + data.g * 0.1 - float(data.b > 0.6) * 0.2;
+  } else if (type == 35) { // spore
+    hue = 0.1;
+    saturation = 0.35;
+    lightness = 0.55 + data.g * 0.2;
+    a = 0.7;
+  } else if (type == 36) { // worm head
+    hue = 0.97;
+    saturation = 0.45;
+    lightness = 0.6;
+  } else if (type == 37) { // worm body
+    hue = 0.98;
+    saturation = 0.4;
+    lightness = 0.75;
+    int segment = int(mod(data.g * 255., 2.) + 0.1);
+    if (segment == 0) {
+      lightness -= 0.08;
+    }
+  } else if (type == 38) { // ant
+    hue = 0.02;
+    saturation = 0.5;
+    lightness = 0.25;
+    if (data.b > 0.58) { // carrying food
+      lightness = 0.4;
+    }
+  } else if (type == 39) { // anthill
+    hue = 0.07;
+    saturation = 0.45;
+    lightness = 0.45 + data.g * 0.2;
+  } else if (type == 40) { // snail
+    hue = 0.08;
+    saturation = 0.5;
+    lightness = 0.35 + data.g * 0.3;
+  } else if (type == 41) { // slime
+    hue = 0.25;
+    saturation = 0.3;
+    lightness = 0.8 + noise * 0.05;
+    a = 0.3 + data.b;
+  } else if (type == 42) { // fish
+    hue = 0.05 + data.g * 0.1;
+    saturation = 0.8;
+    lightness = 0.65;
+  } else if (type == 43) { // kelp
+    hue = 0.2 + data.g * 0.05;
+    saturation = 0.55;
+    lightness = 0.35 + data.g * 0.2;
+  } else if (type == 44) { // grass
+    hue = 0.28 + data.g * 0.06;
+    saturation = 0.6;
+    lightness = 0.45 + data.g * 0.2;
+  } else if (type == 45) { // flower
+    hue = data.g;
+    saturation = 0.75 - data.b * 0.4;
+    lightness = 0.8 - data.b * 0.2;
+  } else if (type == 46) { // vine
+    hue = 0.3 + data.g * 0.08;
+    saturation = 0.55;
+    lightness = 0.4 + data.g * 0.2;
   } else if (type == 8) { // lava
     hue = (data.g * 0.1);
     lightness = 0.7 + data.g * 0.25 + noise * 0.1;
@@ -129,6 +233,19 @@ void main() {
     hue = fract(fract(data.b * 2.) * 0.5) - 0.3;
     saturation = 0.7 * (data.g + 0.4) + data.b * 0.2;
     lightness = 0.9 * (data.g + 0.9);
+  } else if (type == 20) { // glass
+    hue = 0.5;
+    saturation = 0.15;
+    lightness = 0.85 + data.g * 0.1;
+    a = 0.6;
+  } else if (type == 21) { // metal
+    hue = 0.6 - data.b * 0.6;
+    saturation = 0.05 + data.b * 0.8;
+    lightness = 0.3 + data.g * 0.4 + data.b * 0.3;
+  } else if (type == 22) { // rust
+    hue = 0.05;
+    saturation = 0.6;
+    lightness = 0.3 + data.g * 0.3;
   }
   if (isSnapshot == false) {
     lightness *= (0.975 + snoise2(floor(uv * resolution / dpi)) * 0.025);
@@ -7,6 +7,8 @@ import { Universe } from "../crate/pkg";
 
 let fsh = require("./glsl/sand.glsl");
 let vsh = require("./glsl/sandVertex.glsl");
+let projectileFsh = require("./glsl/projectile.glsl");
+let projectileVsh = require("./glsl/projectileVertex.glsl");
 
 // 1. 初始化 WebGL 渲染 (startWebGL)
 // startWebGL 函数接受一个配置对象，初始化 WebGL 渲染，并通过 regl 绘制沙子模拟的状态。
@@ -61,9 +63,48 @@ let startWebGL = ({ canvas, universe, isSnapshot = false }) => {
     count: 3,
   });
 
+  // 飞行中的粒子不在网格里，从 universe.projectiles() 读出来单独画成点。
+  // 每个 Projectile 占 20 字节：x、y、vx、vy 四个 f32，后面是它的 Cell（第一个字节是物种）。
+  let projectileCount = 0;
+  let projectilePositions = [];
+  let projectileSpecies = [];
+  let readProjectiles = () => {
+    projectileCount = universe.projectile_count();
+    let pointer = universe.projectiles();
+    let floats = new Float32Array(memory.buffer, pointer, projectileCount * 5);
+    let bytes = new Uint8Array(memory.buffer, pointer, projectileCount * 20);
+    projectilePositions = [];
+    projectileSpecies = [];
+    for (let i = 0; i < projectileCount; i++) {
+      projectilePositions.push([
+        (2 * floats[i * 5]) / width - 1,
+        1 - (2 * floats[i * 5 + 1]) / height,
+      ]);
+      projectileSpecies.push(bytes[i * 20 + 16]);
+    }
+  };
+
+  let drawProjectiles = regl({
+    frag: projectileFsh,
+    vert: projectileVsh,
+    attributes: {
+      position: () => projectilePositions,
+      species: () => projectileSpecies,
+    },
+    uniforms: {
+      size: ({ viewportWidth }) => viewportWidth / width,
+    },
+    primitive: "points",
+    count: () => projectileCount,
+  });
+
   return () => {
     regl.poll();
     drawSand();
+    readProjectiles();
+    if (projectileCount > 0) {
+      drawProjectiles();
+    }
   };
 };
 